@@ -0,0 +1,104 @@
+//! Register the solutions found in `src`: every `dayNN.rs` or `questNN.rs` file
+//! of an event directory (`year_NNNN` or `story_NN`) is a day, and each of its
+//! `pub fn solve_<name>` functions is a variant besides `solve`
+
+use std::{
+    env,
+    error::Error,
+    fmt::Write,
+    fs, iter,
+    path::{Path, PathBuf},
+};
+
+fn is_event_dir(name: &str) -> bool {
+    ["year_", "story_"].iter().any(|prefix| {
+        name.strip_prefix(prefix)
+            .is_some_and(|id| !id.is_empty() && id.bytes().all(|c| c.is_ascii_digit()))
+    })
+}
+
+/// Module name of a day file, e.g. `day01` for `day01.rs`
+fn get_day_module(path: &Path) -> Option<String> {
+    let name = path.file_name()?.to_str()?.strip_suffix(".rs")?;
+    let number = ["day", "quest"]
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix))?;
+    (!number.is_empty() && number.bytes().all(|c| c.is_ascii_digit())).then(|| name.to_string())
+}
+
+fn get_variants(source: &str) -> Vec<&str> {
+    source
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub fn solve_"))
+        .filter_map(|line| line.split_once('(').map(|(name, _)| name))
+        .collect()
+}
+
+fn sorted_entries(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+    Ok(entries)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    println!("cargo:rerun-if-changed={}", src.display());
+
+    let mut modules = String::new();
+    let mut solutions = String::new();
+    for event_dir in sorted_entries(&src)? {
+        let Some(event) = event_dir.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if !event_dir.is_dir() || !is_event_dir(event) {
+            continue;
+        }
+
+        writeln!(modules, "pub mod {event} {{")?;
+        for day_file in sorted_entries(&event_dir)? {
+            let Some(day) = get_day_module(&day_file) else {
+                continue;
+            };
+            writeln!(
+                modules,
+                "    #[path = {:?}]",
+                day_file.display().to_string()
+            )?;
+            writeln!(modules, "    pub mod {day};")?;
+
+            let source = fs::read_to_string(&day_file)?;
+            let variants = iter::once((String::from("DEFAULT_VARIANT"), String::from("solve")))
+                .chain(
+                    get_variants(&source)
+                        .into_iter()
+                        .map(|name| (format!("{name:?}"), format!("solve_{name}"))),
+                );
+            for (variant, function) in variants {
+                writeln!(
+                    solutions,
+                    "        solution({event:?}, {day:?}, {variant}, |input, part| {{
+            {event}::{day}::{function}(input, part).map(Answer::from)
+        }}),"
+                )?;
+            }
+        }
+        writeln!(modules, "}}")?;
+    }
+
+    let registry = format!(
+        "// Generated by build.rs from the day modules of `src`
+{modules}
+// Some solutions already return an `Answer`
+#[allow(clippy::useless_conversion)]
+fn solutions() -> Vec<Solution> {{
+    vec![
+{solutions}    ]
+}}
+"
+    );
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+    fs::write(out_dir.join("registry.rs"), registry)?;
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Answer of a part. Texts holding an integer are always stored as `Int`, so
+/// that answers compare equal whatever their source
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{n}"),
+            Self::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Int(i64),
+            Text(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Int(n) => Ok(Self::Int(n)),
+            Raw::Text(text) => Ok(text.into()),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(impl From<$int> for Answer {
+            fn from(n: $int) -> Self {
+                Self::Int(n.into())
+            }
+        })*
+    }
+}
+
+impl_from_int!(i32, u16, u32, i64);
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        // Out of range values are still displayed properly
+        i64::try_from(n).map_or_else(|_| Self::Text(n.to_string()), Self::Int)
+    }
+}
+
+/// Texts such as `007` or `+7` are kept as is, as they would not be
+/// displayed the same as an `Int`
+fn parse_int(text: &str) -> Option<i64> {
+    text.parse().ok().filter(|n: &i64| n.to_string() == text)
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        parse_int(&text).map_or(Self::Text(text), Self::Int)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        parse_int(text).map_or_else(|| Self::Text(text.to_string()), Self::Int)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn texts_of_integers_equal_ints() {
+        assert_eq!(Answer::from(String::from("7")), Answer::Int(7));
+        assert_eq!(Answer::from("-12"), Answer::from(-12));
+        assert_eq!("7".parse::<Answer>().unwrap(), Answer::from(7u32));
+        assert_eq!(
+            Answer::from(String::from("abc")),
+            Answer::Text(String::from("abc"))
+        );
+        assert_eq!(Answer::from("007"), Answer::Text(String::from("007")));
+        assert_eq!(Answer::from(u64::MAX), Answer::Text(u64::MAX.to_string()));
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let answers = BTreeMap::from([
+            ("part1", Answer::from("7")),
+            ("part2", Answer::from("RSTU")),
+            ("part3", Answer::from("007")),
+        ]);
+        let toml = toml::to_string(&answers).unwrap();
+
+        assert_eq!(toml, "part1 = 7\npart2 = \"RSTU\"\npart3 = \"007\"\n");
+        assert_eq!(
+            toml::from_str::<BTreeMap<&str, Answer>>(&toml).unwrap(),
+            answers
+        );
+    }
+
+    #[test]
+    fn reads_integers_written_as_strings() {
+        let answers: BTreeMap<String, Answer> = toml::from_str("part1 = \"7\"").unwrap();
+
+        assert_eq!(answers["part1"], Answer::Int(7));
+    }
+}
@@ -0,0 +1,273 @@
+use crate::args::get_day_directory;
+use clap::ValueEnum;
+use everybody_codes::{Answer, Event};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Expected answers of a day, stored in `answers.toml` next to its inputs,
+/// along with the answers of the examples of its statement
+#[derive(Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part1: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part2: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part3: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    example1: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    example2: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    example3: Option<Answer>,
+}
+
+impl Answers {
+    pub fn load(root: &Path, event: Event, day: u8) -> Result<Self, Box<dyn Error>> {
+        load_toml(get_day_file(root, event, day, "answers.toml"))
+    }
+
+    pub fn save(&self, root: &Path, event: Event, day: u8) -> Result<(), Box<dyn Error>> {
+        save_toml(self, root, event, day, "answers.toml")
+    }
+
+    pub fn get(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            3 => self.part3.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: Answer) {
+        match part {
+            1 => self.part1 = Some(answer),
+            2 => self.part2 = Some(answer),
+            3 => self.part3 = Some(answer),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn get_example(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.example1.as_ref(),
+            2 => self.example2.as_ref(),
+            3 => self.example3.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn set_example(&mut self, part: u8, answer: Answer) {
+        match part {
+            1 => self.example1 = Some(answer),
+            2 => self.example2 = Some(answer),
+            3 => self.example3 = Some(answer),
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Hint given by the site alongside a wrong answer
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum Bound {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Rejection {
+    pub answer: Answer,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bound: Option<Bound>,
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.bound {
+            None => write!(f, "{}", self.answer),
+            Some(Bound::TooHigh) => write!(f, "{} (too high)", self.answer),
+            Some(Bound::TooLow) => write!(f, "{} (too low)", self.answer),
+        }
+    }
+}
+
+/// Answers of a day rejected by the site, stored in `rejected.toml` next to its inputs
+#[derive(Default, Serialize, Deserialize)]
+pub struct Rejections {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part1: Vec<Rejection>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part2: Vec<Rejection>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part3: Vec<Rejection>,
+}
+
+impl Rejections {
+    pub fn load(root: &Path, event: Event, day: u8) -> Result<Self, Box<dyn Error>> {
+        load_toml(get_day_file(root, event, day, "rejected.toml"))
+    }
+
+    pub fn save(&self, root: &Path, event: Event, day: u8) -> Result<(), Box<dyn Error>> {
+        save_toml(self, root, event, day, "rejected.toml")
+    }
+
+    pub fn get(&self, part: u8) -> &[Rejection] {
+        match part {
+            1 => &self.part1,
+            2 => &self.part2,
+            3 => &self.part3,
+            _ => &[],
+        }
+    }
+
+    pub fn add(&mut self, part: u8, rejection: Rejection) {
+        match part {
+            1 => self.part1.push(rejection),
+            2 => self.part2.push(rejection),
+            3 => self.part3.push(rejection),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Reasons why the site would reject `answer`, based on the previous rejections
+    pub fn warnings(&self, part: u8, answer: &Answer) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for rejection in self.get(part) {
+            if rejection.answer == *answer {
+                warnings.push(format!("answer {answer} was already rejected"));
+                continue;
+            }
+
+            let (Answer::Int(value), Answer::Int(bound)) = (answer, &rejection.answer) else {
+                continue;
+            };
+            match rejection.bound {
+                Some(Bound::TooHigh) if value > bound => {
+                    warnings.push(format!("answer {answer} is above rejected {rejection}"))
+                }
+                Some(Bound::TooLow) if value < bound => {
+                    warnings.push(format!("answer {answer} is below rejected {rejection}"))
+                }
+                _ => (),
+            }
+        }
+        warnings
+    }
+}
+
+/// Keys decrypting the notes of a day, stored in `keys.toml` next to its inputs
+#[derive(Default, Serialize, Deserialize)]
+pub struct Keys {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key3: Option<String>,
+}
+
+impl Keys {
+    pub fn load(root: &Path, event: Event, day: u8) -> Result<Self, Box<dyn Error>> {
+        load_toml(get_day_file(root, event, day, "keys.toml"))
+    }
+
+    pub fn save(&self, root: &Path, event: Event, day: u8) -> Result<(), Box<dyn Error>> {
+        save_toml(self, root, event, day, "keys.toml")
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.key1.as_deref(),
+            2 => self.key2.as_deref(),
+            3 => self.key3.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+pub fn get_day_file(root: &Path, event: Event, day: u8, name: &str) -> PathBuf {
+    get_day_directory(root, event, day).join(name)
+}
+
+fn load_toml<T: DeserializeOwned + Default>(path: PathBuf) -> Result<T, Box<dyn Error>> {
+    match fs::read_to_string(&path) {
+        Ok(content) => toml::from_str(&content)
+            .map_err(|err| format!("invalid file {}: {err}", path.display()).into()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn save_toml<T: Serialize>(
+    value: &T,
+    root: &Path,
+    event: Event,
+    day: u8,
+    name: &str,
+) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(get_day_directory(root, event, day))?;
+    fs::write(
+        get_day_file(root, event, day, name),
+        toml::to_string(value)?,
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rejections() -> Rejections {
+        toml::from_str(
+            r#"
+            part1 = [
+                { answer = 712 },
+                { answer = 900, bound = "too_high" },
+                { answer = 100, bound = "too_low" },
+            ]
+            part2 = [{ answer = "RSTU" }]
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn warns_about_repeated_answers() {
+        let rejections = rejections();
+
+        assert_eq!(
+            rejections.warnings(1, &Answer::from(String::from("712"))),
+            ["answer 712 was already rejected"]
+        );
+        assert_eq!(
+            rejections.warnings(2, &Answer::from("RSTU")),
+            ["answer RSTU was already rejected"]
+        );
+        assert!(rejections.warnings(3, &Answer::from(712)).is_empty());
+    }
+
+    #[test]
+    fn warns_about_answers_beyond_bounds() {
+        let rejections = rejections();
+
+        assert_eq!(
+            rejections.warnings(1, &Answer::from(901)),
+            ["answer 901 is above rejected 900 (too high)"]
+        );
+        assert_eq!(
+            rejections.warnings(1, &Answer::from("99")),
+            ["answer 99 is below rejected 100 (too low)"]
+        );
+        assert!(rejections.warnings(1, &Answer::from(500)).is_empty());
+        assert_eq!(
+            rejections.warnings(1, &Answer::from(900)),
+            ["answer 900 was already rejected"]
+        );
+    }
+}
@@ -1,90 +1,765 @@
-use clap::Parser;
+use crate::{answers::Bound, config::Config, executor, new::AnswerType};
+
+use clap::{
+    error::ErrorKind, parser::ValueSource, Args, CommandFactory, FromArgMatches, Parser,
+    Subcommand, ValueEnum,
+};
+use everybody_codes::{find_variant, registered_days, Answer, Event, DEFAULT_VARIANT};
+use serde::Deserialize;
 use std::{
+    env,
     error::Error,
-    fs, io,
+    fs,
+    io::{self, IsTerminal, Read},
+    num::NonZeroUsize,
     path::{Path, PathBuf},
 };
 
 #[derive(Parser)]
 #[command(version, about)]
-struct RunOptionArgs {
-    #[arg(short, long = "input")]
-    input_file: Option<PathBuf>,
+struct Cli {
+    #[command(subcommand)]
+    command: Option<SubCommand>,
 
+    #[command(flatten)]
+    run: RunOptionArgs,
+
+    /// Print the wall-clock time of each run
     #[arg(short, long)]
-    year: Option<u32>,
+    time: bool,
+
+    /// Output format of the results [default: text]
+    #[arg(short, long, value_enum)]
+    format: Option<Format>,
+
+    /// Run again whenever the source of the day or its input changes
+    #[arg(short, long, conflicts_with = "format")]
+    watch: bool,
+
+    /// Run every variant of the selected parts and fail if their answers differ
+    #[arg(long, conflicts_with_all = ["format", "watch", "variant"])]
+    cross_check: bool,
+
+    /// Number of runs executed in parallel [default: number of cores]
+    #[arg(short, long, conflicts_with_all = ["watch", "cross_check"])]
+    jobs: Option<NonZeroUsize>,
+
+    /// Directory containing the `input` tree [default: .]
+    #[arg(long, global = true, env = "EC_ROOT")]
+    root: Option<PathBuf>,
+
+    /// Config file of the runner [default: ec.toml if it exists]
+    #[arg(long, global = true, env = "EC_CONFIG")]
+    config: Option<PathBuf>,
+}
+
+impl Cli {
+    /// Parse the command line, where the run mode flags conflict with
+    /// subcommands but global options may come before or after them
+    fn parse_args() -> Self {
+        let mut command = Self::command();
+        let matches = command.get_matches_mut();
+        if let Some(subcommand) = matches.subcommand_name() {
+            let run_arg = command.get_arguments().find(|arg| {
+                !arg.is_global_set()
+                    && matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine)
+            });
+            if let Some(arg) = run_arg {
+                let message = format!("subcommand '{subcommand}' cannot be used with '{arg}'");
+                command.error(ErrorKind::ArgumentConflict, message).exit();
+            }
+        }
+        Self::from_arg_matches(&matches).unwrap_or_else(|err| err.exit())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Copy, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Color {
+    /// Use colors if the output is a terminal
+    Auto,
+    Always,
+    Never,
+}
+
+impl Color {
+    pub fn enabled(self) -> bool {
+        match self {
+            Self::Auto => io::stdout().is_terminal(),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum SubCommand {
+    /// Run solutions several times and report min/median/max durations
+    Bench {
+        #[command(flatten)]
+        run: RunOptionArgs,
+
+        /// Number of runs per solution
+        #[arg(short = 'n', long, default_value_t = 10)]
+        runs: u32,
+    },
+    /// Compare computed answers against the recorded ones
+    Check {
+        #[command(flatten)]
+        run: RunOptionArgs,
+
+        /// Save computed answers of parts without a recorded answer, on the
+        /// inputs of the site only
+        #[arg(short, long)]
+        record: bool,
+
+        /// Number of runs executed in parallel [default: number of cores]
+        #[arg(short, long)]
+        jobs: Option<NonZeroUsize>,
+    },
+    /// Record an answer rejected by the site
+    Reject {
+        #[command(flatten)]
+        part: PartArgs,
+
+        /// The rejected answer
+        #[arg(value_parser = str::parse::<Answer>)]
+        answer: Answer,
+
+        /// Bound given by the site for the answer
+        #[arg(short, long, value_enum)]
+        bound: Option<Bound>,
+    },
+    /// List registered days with their inputs, examples and recorded answers
+    List {
+        #[command(flatten)]
+        event: EventArgs,
+    },
+    /// Download the notes of a day and decrypt the inputs of its unlocked parts
+    Fetch {
+        #[command(flatten)]
+        day: DayArgs,
+
+        #[command(flatten)]
+        site: SiteArgs,
+    },
+    /// Submit the answer of a part to the site and record its verdict
+    Submit {
+        #[command(flatten)]
+        part: PartArgs,
+
+        #[command(flatten)]
+        site: SiteArgs,
+
+        /// Submit even if the answer is known to be wrong
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Show the statement of a part, downloading it unless already cached
+    Read {
+        #[command(flatten)]
+        day: DayArgs,
+
+        /// Part to show, defaults to the latest unlocked one
+        #[arg(short, long)]
+        part: Option<u8>,
+
+        #[command(flatten)]
+        site: SiteArgs,
+
+        /// Download the statements even if already cached
+        #[arg(short, long)]
+        refresh: bool,
+
+        /// Whether to render emphasis and code with colors [default: auto]
+        #[arg(long, value_enum)]
+        color: Option<Color>,
+    },
+    /// Write the examples of the cached statements of a day and their answers
+    Extract {
+        #[command(flatten)]
+        day: DayArgs,
+
+        /// Overwrite existing example inputs
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Scaffold the solution of a new day, registered at the next build. The
+    /// day defaults to the one after the latest registered day
+    New {
+        #[command(flatten)]
+        day: DayArgs,
+
+        /// Type returned by the solution
+        #[arg(short, long, value_enum, default_value_t = AnswerType::U32)]
+        answer_type: AnswerType,
+
+        /// Source directory of the crate
+        #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/src"))]
+        src: PathBuf,
+    },
+}
+
+#[derive(Args)]
+struct SiteArgs {
+    /// File containing the session cookie of the site, defaults to
+    /// `~/.config/everybody_codes/cookie`
+    #[arg(long, env = "EC_COOKIE_FILE")]
+    cookie_file: Option<PathBuf>,
+
+    /// Base URL replacing the site and its CDN, e.g. a local test server
+    #[arg(long, env = "EC_BASE_URL")]
+    base_url: Option<String>,
+}
+
+#[derive(Args)]
+struct EventArgs {
+    /// Year of the event, or `storyN` for a story
+    #[arg(short, long, visible_alias = "year", short_alias = 'y')]
+    event: Option<Event>,
+}
+
+#[derive(Args)]
+struct DayArgs {
+    #[command(flatten)]
+    event: EventArgs,
 
     #[arg(short, long)]
     day: Option<u8>,
+}
+
+impl DayArgs {
+    fn event(&self, config: &Config) -> Option<Event> {
+        self.event.event.or(config.event)
+    }
+
+    /// Event and day of a registered solution
+    fn get_registered_day(&self, config: &Config) -> Result<(Event, u8), Box<dyn Error>> {
+        get_event_and_day(self.event(config), self.day)
+    }
+
+    /// Event and day of a command on the site, where a day given along with
+    /// its event need not be registered yet
+    fn get_site_day(&self, config: &Config) -> Result<(Event, u8), Box<dyn Error>> {
+        match (self.event(config), self.day) {
+            (Some(event), Some(day)) => Ok((event, day)),
+            (event, day) => get_event_and_day(event, day),
+        }
+    }
+}
+
+#[derive(Args)]
+struct PartArgs {
+    #[command(flatten)]
+    day: DayArgs,
 
     #[arg(short, long)]
     part: Option<u8>,
 }
 
+#[derive(Args)]
+struct RunOptionArgs {
+    /// Input file, or `-` to read it from stdin
+    #[arg(short, long = "input")]
+    input_file: Option<PathBuf>,
+
+    #[command(flatten)]
+    day: DayArgs,
+
+    #[arg(short, long)]
+    part: Option<u8>,
+
+    /// Run on the example of the statement and compare with its answer
+    #[arg(long, conflicts_with = "input_file")]
+    example: bool,
+
+    /// Variant of the solution to run, as shown by `list`
+    #[arg(long)]
+    variant: Option<String>,
+
+    /// Run every part of the day that has an input file
+    #[arg(short, long, conflicts_with_all = ["input_file", "part"])]
+    all_parts: bool,
+
+    /// Run every registered day and part that has an input file, only of the
+    /// given or configured event if any
+    #[arg(long, conflicts_with_all = ["input_file", "day", "part", "all_parts"])]
+    all: bool,
+}
+
+pub enum Selection {
+    Single(RunArgs),
+    Day(Vec<RunArgs>),
+    All(Vec<RunArgs>),
+}
+
+pub enum Command {
+    Run {
+        selection: Selection,
+        time: bool,
+        format: Format,
+        watch: bool,
+        jobs: NonZeroUsize,
+    },
+    CrossCheck {
+        selection: Selection,
+    },
+    Bench {
+        selection: Selection,
+        runs: u32,
+    },
+    Check {
+        selection: Selection,
+        record: bool,
+        jobs: NonZeroUsize,
+    },
+    Reject {
+        root: PathBuf,
+        event: Event,
+        day: u8,
+        part: u8,
+        answer: Answer,
+        bound: Option<Bound>,
+    },
+    List {
+        root: PathBuf,
+        event: Option<Event>,
+    },
+    Fetch {
+        root: PathBuf,
+        event: Event,
+        day: u8,
+        cookie_file: PathBuf,
+        base_url: Option<String>,
+    },
+    Submit {
+        args: RunArgs,
+        cookie_file: PathBuf,
+        base_url: Option<String>,
+        force: bool,
+    },
+    Read {
+        root: PathBuf,
+        event: Event,
+        day: u8,
+        part: Option<u8>,
+        cookie_file: PathBuf,
+        base_url: Option<String>,
+        refresh: bool,
+        color: bool,
+    },
+    Extract {
+        root: PathBuf,
+        event: Event,
+        day: u8,
+        force: bool,
+    },
+    New {
+        src: PathBuf,
+        root: PathBuf,
+        event: Event,
+        day: u8,
+        answer_type: AnswerType,
+    },
+}
+
+impl Command {
+    pub fn parse() -> Result<Self, Box<dyn Error>> {
+        let cli = Cli::parse_args();
+        let config = Config::load(cli.config.as_deref())?;
+        let root = cli
+            .root
+            .or_else(|| config.root.clone())
+            .unwrap_or_else(|| PathBuf::from("."));
+        let cookie_file = |site: SiteArgs| {
+            site.cookie_file
+                .or_else(|| config.cookie_file.clone())
+                .map_or_else(get_default_cookie_file, Ok)
+        };
+
+        match cli.command {
+            None if cli.cross_check => Ok(Self::CrossCheck {
+                selection: Selection::from_options(root, cli.run, &config)?,
+            }),
+            None => Ok(Self::Run {
+                selection: Selection::from_options(root, cli.run, &config)?,
+                time: cli.time,
+                format: cli.format.or(config.format).unwrap_or(Format::Text),
+                watch: cli.watch,
+                jobs: cli.jobs.unwrap_or_else(executor::default_jobs),
+            }),
+            Some(SubCommand::Bench { run, runs }) => Ok(Self::Bench {
+                selection: Selection::from_options(root, run, &config)?,
+                runs: runs.max(1),
+            }),
+            Some(SubCommand::Check { run, record, jobs }) => Ok(Self::Check {
+                selection: Selection::from_options(root, run, &config)?,
+                record,
+                jobs: jobs.unwrap_or_else(executor::default_jobs),
+            }),
+            Some(SubCommand::Reject {
+                part,
+                answer,
+                bound,
+            }) => {
+                let (event, day) = part.day.get_registered_day(&config)?;
+                Ok(Self::Reject {
+                    part: get_part(&root, event, day, part.part, false, config.parts())?,
+                    root,
+                    event,
+                    day,
+                    answer,
+                    bound,
+                })
+            }
+            Some(SubCommand::List { event }) => Ok(Self::List {
+                root,
+                event: event.event,
+            }),
+            Some(SubCommand::Fetch { day, site }) => {
+                let (event, day) = day.get_site_day(&config)?;
+                Ok(Self::Fetch {
+                    root,
+                    event,
+                    day,
+                    base_url: site.base_url.clone(),
+                    cookie_file: cookie_file(site)?,
+                })
+            }
+            Some(SubCommand::Submit { part, site, force }) => Ok(Self::Submit {
+                args: RunArgs::from_part(root, part, &config)?,
+                base_url: site.base_url.clone(),
+                cookie_file: cookie_file(site)?,
+                force,
+            }),
+            Some(SubCommand::Read {
+                day,
+                part,
+                site,
+                refresh,
+                color,
+            }) => {
+                let (event, day) = day.get_site_day(&config)?;
+                if let Some(part) = part.filter(|part| !(1..=3).contains(part)) {
+                    return Err(format!("invalid part {part}").into());
+                }
+                Ok(Self::Read {
+                    root,
+                    event,
+                    day,
+                    part,
+                    base_url: site.base_url.clone(),
+                    cookie_file: cookie_file(site)?,
+                    refresh,
+                    color: color.or(config.color).unwrap_or(Color::Auto).enabled(),
+                })
+            }
+            Some(SubCommand::Extract { day, force }) => {
+                let (event, day) = day.get_site_day(&config)?;
+                Ok(Self::Extract {
+                    root,
+                    event,
+                    day,
+                    force,
+                })
+            }
+            Some(SubCommand::New {
+                day,
+                answer_type,
+                src,
+            }) => {
+                let (event, day) = get_new_event_and_day(day.event(&config), day.day)?;
+                Ok(Self::New {
+                    src,
+                    root,
+                    event,
+                    day,
+                    answer_type,
+                })
+            }
+        }
+    }
+}
+
+impl Selection {
+    fn from_options(
+        root: PathBuf,
+        args: RunOptionArgs,
+        config: &Config,
+    ) -> Result<Self, Box<dyn Error>> {
+        let parts = config.parts();
+        let event = args.day.event(config);
+        if args.all {
+            Ok(Self::All(RunArgs::all(root, &args, event, parts)))
+        } else if args.all_parts {
+            RunArgs::all_parts(root, &args, event, parts).map(Self::Day)
+        } else {
+            RunArgs::from_options(root, args, config).map(Self::Single)
+        }
+    }
+
+    pub fn runs(&self) -> &[RunArgs] {
+        match self {
+            Self::Single(args) => std::slice::from_ref(args),
+            Self::Day(runs) | Self::All(runs) => runs,
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct RunArgs {
-    pub year: u32,
+    pub event: Event,
     pub day: u8,
     pub part: u8,
     pub input_file: PathBuf,
+    pub root: PathBuf,
+    /// Whether the run uses the example of the statement
+    pub example: bool,
+    pub variant: &'static str,
 }
 
 impl RunArgs {
-    pub fn parse() -> Result<Self, Box<dyn Error>> {
-        let args = RunOptionArgs::parse();
-
-        let year = match args.year {
-            Some(year) => year,
-            None => get_max_year_directory("src")?,
-        };
-        let day = match args.day {
-            Some(day) => day,
-            None => get_max_day_file(&format!("src/year_{year}"))?,
-        };
+    fn from_options(
+        root: PathBuf,
+        args: RunOptionArgs,
+        config: &Config,
+    ) -> Result<Self, Box<dyn Error>> {
+        let (event, day) = args.day.get_registered_day(config)?;
+        let variant = get_variant(event, day, args.variant.as_deref())?;
 
-        let source_file = format!("src/year_{year}/day{day:02}.rs");
-        if !Path::new(&source_file).exists() {
-            return Err(io::Error::new(
-                io::ErrorKind::NotFound,
-                format!("no source file for year {year} day {day:02}"),
-            )
-            .into());
-        }
-
-        let part = match args.part {
-            Some(part) if (1..=3).contains(&part) => part,
-            Some(part) => Err(format!("invalid part {part}"))?,
-            None => get_default_part(year, day)?,
-        };
+        let part = get_part(&root, event, day, args.part, args.example, config.parts())?;
 
         let input_file = args
             .input_file
-            .unwrap_or_else(|| get_default_input(year, day, part));
+            .unwrap_or_else(|| get_input(&root, event, day, part, args.example));
 
-        if !Path::new(&input_file).exists() {
+        if input_file != Path::new("-") && !input_file.exists() {
+            let kind = if args.example {
+                "example"
+            } else {
+                "input file"
+            };
             return Err(io::Error::new(
                 io::ErrorKind::NotFound,
-                format!("no input file for year {year} day {day:02} part {part}"),
+                format!("no {kind} for {event} day {day:02} part {part}"),
             )
             .into());
         }
 
         Ok(Self {
-            year,
+            event,
             day,
             part,
             input_file,
+            root,
+            example: args.example,
+            variant,
         })
     }
+
+    /// Run of a part on its default input
+    fn from_part(root: PathBuf, args: PartArgs, config: &Config) -> Result<Self, Box<dyn Error>> {
+        Self::from_options(
+            root,
+            RunOptionArgs {
+                input_file: None,
+                day: args.day,
+                part: args.part,
+                example: false,
+                variant: None,
+                all_parts: false,
+                all: false,
+            },
+            config,
+        )
+    }
+
+    pub fn read_input(&self) -> io::Result<String> {
+        if self.input_file == Path::new("-") {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        } else {
+            fs::read_to_string(&self.input_file)
+        }
+    }
+
+    /// Whether the run uses the real input of its day
+    pub fn uses_default_input(&self) -> bool {
+        self.input_file == get_default_input(&self.root, self.event, self.day, self.part)
+    }
+
+    fn all_parts(
+        root: PathBuf,
+        args: &RunOptionArgs,
+        event: Option<Event>,
+        parts: &[u8],
+    ) -> Result<Vec<Self>, Box<dyn Error>> {
+        let (event, day) = get_event_and_day(event, args.day.day)?;
+        let variant = get_variant(event, day, args.variant.as_deref())?;
+        let example = args.example;
+
+        let runs: Vec<Self> = parts
+            .iter()
+            .map(|&part| Self {
+                event,
+                day,
+                part,
+                input_file: get_input(&root, event, day, part, example),
+                root: root.clone(),
+                example,
+                variant,
+            })
+            .filter(|args| args.input_file.exists())
+            .collect();
+
+        if runs.is_empty() {
+            let kind = if example { "example" } else { "input file" };
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no {kind} for {event} day {day:02}"),
+            )
+            .into());
+        }
+        Ok(runs)
+    }
+
+    /// Runs of every registered day, restricted to the days implementing the
+    /// variant if one is given
+    fn all(root: PathBuf, args: &RunOptionArgs, event: Option<Event>, parts: &[u8]) -> Vec<Self> {
+        let example = args.example;
+        registered_days()
+            .into_iter()
+            .filter(|&(solution_event, _)| event.is_none_or(|event| event == solution_event))
+            .filter_map(|(event, day)| {
+                let variant = get_variant(event, day, args.variant.as_deref()).ok()?;
+                Some((event, day, variant))
+            })
+            .flat_map(|(event, day, variant)| {
+                parts.iter().map(move |&part| (event, day, variant, part))
+            })
+            .map(|(event, day, variant, part)| Self {
+                event,
+                day,
+                part,
+                input_file: get_input(&root, event, day, part, example),
+                root: root.clone(),
+                example,
+                variant,
+            })
+            .filter(|args| args.input_file.exists())
+            .collect()
+    }
+}
+
+/// Event used when none is given nor configured: the latest registered year,
+/// or the latest story if no year is registered
+fn get_default_event(days: &[(Event, u8)]) -> Option<Event> {
+    days.iter()
+        .map(|&(event, _)| event)
+        .max_by_key(|&event| (matches!(event, Event::Year(_)), event.id()))
+}
+
+fn get_event_and_day(event: Option<Event>, day: Option<u8>) -> Result<(Event, u8), Box<dyn Error>> {
+    let days = registered_days();
+
+    let event = match event {
+        Some(event) => event,
+        None => get_default_event(&days).ok_or("no registered solution")?,
+    };
+    let day = match day {
+        Some(day) => day,
+        None => days
+            .iter()
+            .filter(|&&(solution_event, _)| solution_event == event)
+            .map(|&(_, day)| day)
+            .max()
+            .ok_or_else(|| format!("no registered solution for {event}"))?,
+    };
+
+    find_variant(event, day, DEFAULT_VARIANT)?;
+    Ok((event, day))
+}
+
+fn get_new_event_and_day(
+    event: Option<Event>,
+    day: Option<u8>,
+) -> Result<(Event, u8), Box<dyn Error>> {
+    let days = registered_days();
+
+    let event = match event {
+        Some(event) => event,
+        None => {
+            get_default_event(&days).ok_or("no registered solution, the event must be given")?
+        }
+    };
+    let day = match day {
+        Some(0) => Err("invalid day 0")?,
+        Some(day) => day,
+        None => days
+            .iter()
+            .filter(|&&(solution_event, _)| solution_event == event)
+            .map(|&(_, day)| day + 1)
+            .max()
+            .unwrap_or(1),
+    };
+    if day > event.nb_quests() {
+        return Err(format!("{event} only has {} quests", event.nb_quests()).into());
+    }
+
+    Ok((event, day))
+}
+
+/// Registered variant of a day with the given name, or the default one
+fn get_variant(
+    event: Event,
+    day: u8,
+    variant: Option<&str>,
+) -> Result<&'static str, Box<dyn Error>> {
+    Ok(find_variant(
+        event,
+        day,
+        variant.unwrap_or(DEFAULT_VARIANT),
+    )?)
+}
+
+fn get_default_cookie_file() -> Result<PathBuf, Box<dyn Error>> {
+    let home = env::var_os("HOME").ok_or("no HOME directory to find the cookie file")?;
+    Ok(PathBuf::from(home).join(".config/everybody_codes/cookie"))
+}
+
+fn get_part(
+    root: &Path,
+    event: Event,
+    day: u8,
+    part: Option<u8>,
+    example: bool,
+    parts: &[u8],
+) -> Result<u8, Box<dyn Error>> {
+    match part {
+        Some(part) if (1..=3).contains(&part) => Ok(part),
+        Some(part) => Err(format!("invalid part {part}"))?,
+        None => get_default_part(root, event, day, example, parts),
+    }
 }
 
-fn read_max_entry<T, P, F>(path: &str, predicate: P, parse_fn: F) -> Result<T, Box<dyn Error>>
+fn read_max_entry<T, P, F>(path: &Path, predicate: P, parse_fn: F) -> Result<T, Box<dyn Error>>
 where
     T: Ord,
     P: Fn(&fs::DirEntry) -> bool,
     F: Fn(&str) -> Option<T>,
 {
+    let path_name = path.display();
     fs::read_dir(path)
-        .map_err(|_| io::Error::new(io::ErrorKind::NotFound, format!("no directory {path}")))?
+        .map_err(|_| io::Error::new(io::ErrorKind::NotFound, format!("no directory {path_name}")))?
         .filter_map(|entry| {
             entry.ok().and_then(|e| {
                 let file_name_os = e.file_name();
@@ -94,34 +769,55 @@ where
         })
         .max()
         .ok_or_else(|| {
-            io::Error::new(io::ErrorKind::NotFound, format!("no valid entry in {path}")).into()
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no valid entry in {path_name}"),
+            )
+            .into()
         })
 }
 
-fn get_max_year_directory(path: &str) -> Result<u32, Box<dyn Error>> {
+/// Latest of the given parts with an input file, or with an example if
+/// `example` is set
+fn get_default_part(
+    root: &Path,
+    event: Event,
+    day: u8,
+    example: bool,
+    parts: &[u8],
+) -> Result<u8, Box<dyn Error>> {
+    let suffix = if example { ".example.txt" } else { ".txt" };
     read_max_entry(
-        path,
-        |e| e.file_type().is_ok_and(|e| e.is_dir()),
-        |name| name.strip_prefix("year_")?.parse().ok(),
+        &get_day_directory(root, event, day),
+        |e| e.file_type().is_ok_and(|e| e.is_file()),
+        |name| {
+            name.strip_prefix("part")?
+                .strip_suffix(suffix)?
+                .parse()
+                .ok()
+                .filter(|part| parts.contains(part))
+        },
     )
 }
 
-fn get_max_day_file(path: &str) -> Result<u8, Box<dyn Error>> {
-    read_max_entry(
-        path,
-        |e| e.file_type().is_ok_and(|e| e.is_file()),
-        |name| name.strip_prefix("day")?.strip_suffix(".rs")?.parse().ok(),
-    )
+pub fn get_day_directory(root: &Path, event: Event, day: u8) -> PathBuf {
+    root.join("input")
+        .join(event.module_name())
+        .join(event.quest_name(day))
 }
 
-fn get_default_part(year: u32, day: u8) -> Result<u8, Box<dyn Error>> {
-    read_max_entry(
-        format!("input/year_{year}/day{day:02}").as_str(),
-        |e| e.file_type().is_ok_and(|e| e.is_file()),
-        |name| name.strip_prefix("part")?.strip_suffix(".txt")?.parse().ok(),
-    )
+pub fn get_default_input(root: &Path, event: Event, day: u8, part: u8) -> PathBuf {
+    get_day_directory(root, event, day).join(format!("part{part}.txt"))
+}
+
+pub fn get_example_input(root: &Path, event: Event, day: u8, part: u8) -> PathBuf {
+    get_day_directory(root, event, day).join(format!("part{part}.example.txt"))
 }
 
-fn get_default_input(year: u32, day: u8, part: u8) -> PathBuf {
-    format!("input/year_{year}/day{day:02}/part{part}.txt").into()
+fn get_input(root: &Path, event: Event, day: u8, part: u8, example: bool) -> PathBuf {
+    if example {
+        get_example_input(root, event, day, part)
+    } else {
+        get_default_input(root, event, day, part)
+    }
 }
@@ -0,0 +1,110 @@
+use crate::{answers::Answers, args::RunArgs, executor, report};
+use everybody_codes::Event;
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    error::Error,
+    fmt,
+    num::NonZeroUsize,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Recorded,
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status = match self {
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::Missing => "MISSING",
+            Self::Recorded => "RECORDED",
+            Self::Error => "ERROR",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// Run the solutions in parallel and compare them against the answers files,
+/// returning whether no solution failed
+pub fn check(runs: &[RunArgs], record: bool, jobs: NonZeroUsize) -> Result<bool, Box<dyn Error>> {
+    if runs.is_empty() {
+        println!("No registered solution has an input file");
+        return Ok(true);
+    }
+
+    let mut answers: BTreeMap<(Event, u8), (Answers, bool)> = BTreeMap::new();
+    let mut statuses = Vec::new();
+    let mut rows = Vec::new();
+    let (records, _) = executor::run_all(runs, jobs);
+    for (args, run_record) in runs.iter().zip(records) {
+        let (day_answers, modified) = match answers.entry((args.event, args.day)) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                entry.insert((Answers::load(&args.root, args.event, args.day)?, false))
+            }
+        };
+
+        // Recorded answers are only those of the examples and of the inputs
+        // of the site, not of custom inputs
+        let expected = if args.example {
+            day_answers.get_example(args.part).cloned()
+        } else if args.uses_default_input() {
+            day_answers.get(args.part).cloned()
+        } else {
+            None
+        };
+        let status = match (&run_record.answer, &expected) {
+            (None, _) => Status::Error,
+            (Some(answer), Some(expected)) if answer == expected => Status::Pass,
+            (Some(_), Some(_)) => Status::Fail,
+            // Answers of examples only come from their statement
+            (Some(answer), None) if record && args.uses_default_input() => {
+                day_answers.set(args.part, answer.clone());
+                *modified = true;
+                Status::Recorded
+            }
+            (Some(_), None) => Status::Missing,
+        };
+
+        let mut row = report::run_label(args);
+        row.extend([
+            run_record.outcome(),
+            expected
+                .map(|answer| answer.to_string())
+                .unwrap_or_default(),
+            status.to_string(),
+        ]);
+        rows.push(row);
+        statuses.push(status);
+    }
+
+    // Runs of a selection all share the same root
+    let root = &runs[0].root;
+    for ((event, day), (day_answers, modified)) in &answers {
+        if *modified {
+            day_answers.save(root, *event, *day)?;
+        }
+    }
+
+    report::print_table(
+        &["Event", "Day", "Part", "Answer", "Expected", "Status"],
+        &rows,
+    );
+
+    let count = |status| statuses.iter().filter(|&&s| s == status).count();
+    println!(
+        "\n{} passed, {} failed, {} missing, {} recorded, {} errors",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing),
+        count(Status::Recorded),
+        count(Status::Error),
+    );
+
+    Ok(count(Status::Fail) + count(Status::Error) == 0)
+}
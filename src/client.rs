@@ -0,0 +1,270 @@
+use crate::answers::Keys;
+use everybody_codes::{Answer, Event};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::{collections::BTreeMap, error::Error, fs, path::Path};
+
+const SITE_URL: &str = "https://everybody.codes";
+const CDN_URL: &str = "https://everybody-codes.b-cdn.net";
+
+/// Error statuses of a submission taken as a refusal to answer for now (part
+/// still locked, answers sent too quickly), rather than as a failure
+const TOO_EARLY_STATUSES: [u16; 4] = [409, 423, 425, 429];
+
+/// Client of the Everybody Codes site, authenticated with a session cookie
+pub struct Client {
+    agent: ureq::Agent,
+    site_url: String,
+    cdn_url: String,
+    cookie: String,
+}
+
+#[derive(Deserialize)]
+struct User {
+    seed: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SubmitResponse {
+    correct: bool,
+    #[serde(default)]
+    length_correct: bool,
+    #[serde(default)]
+    first_correct: bool,
+}
+
+/// Verdict of the site on a submitted answer
+pub enum Verdict {
+    Correct,
+    Wrong {
+        length_correct: bool,
+        first_correct: bool,
+    },
+    /// The part is locked or the site asks to wait before answering again
+    TooEarly(String),
+}
+
+impl Client {
+    /// Read the session cookie from `cookie_file`. A `base_url` replaces both
+    /// the site and its CDN, e.g. to use a local server instead
+    pub fn new(cookie_file: &Path, base_url: Option<&str>) -> Result<Self, Box<dyn Error>> {
+        let cookie = fs::read_to_string(cookie_file)
+            .map_err(|err| format!("cannot read cookie file {}: {err}", cookie_file.display()))?;
+        let base_url = base_url.map(|url| url.trim_end_matches('/'));
+
+        Ok(Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("everybody_codes/", env!("CARGO_PKG_VERSION")))
+                .build(),
+            site_url: base_url.unwrap_or(SITE_URL).to_string(),
+            cdn_url: base_url.unwrap_or(CDN_URL).to_string(),
+            cookie: format!("everybody-codes={}", cookie.trim()),
+        })
+    }
+
+    fn get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        with_cookie: bool,
+    ) -> Result<T, Box<dyn Error>> {
+        let mut request = self.agent.get(url);
+        if with_cookie {
+            request = request.set("Cookie", &self.cookie);
+        }
+        request
+            .call()?
+            .into_json()
+            .map_err(|err| format!("invalid response from {url}: {err}").into())
+    }
+
+    pub fn get_seed(&self) -> Result<u64, Box<dyn Error>> {
+        let user: User = self.get_json(&format!("{}/api/user/me", self.site_url), true)?;
+        Ok(user.seed)
+    }
+
+    /// Encrypted notes of every part of a quest, as hexadecimal strings
+    /// indexed by part number
+    pub fn get_encrypted_notes(
+        &self,
+        event: Event,
+        quest: u8,
+        seed: u64,
+    ) -> Result<BTreeMap<String, String>, Box<dyn Error>> {
+        let url = format!(
+            "{}/assets/{}/{quest}/input/{seed}.json",
+            self.cdn_url,
+            event.id()
+        );
+        self.get_json(&url, false)
+    }
+
+    /// Encrypted statements of every part of a quest, as hexadecimal strings
+    /// indexed by part number
+    pub fn get_encrypted_descriptions(
+        &self,
+        event: Event,
+        quest: u8,
+        seed: u64,
+    ) -> Result<BTreeMap<String, String>, Box<dyn Error>> {
+        let url = format!(
+            "{}/assets/{}/{quest}/description/{seed}.json",
+            self.cdn_url,
+            event.id()
+        );
+        self.get_json(&url, false)
+    }
+
+    /// Keys of the parts unlocked so far
+    pub fn get_keys(&self, event: Event, quest: u8) -> Result<Keys, Box<dyn Error>> {
+        let url = format!("{}/api/event/{}/quest/{quest}", self.site_url, event.id());
+        self.get_json(&url, true)
+    }
+
+    pub fn submit_answer(
+        &self,
+        event: Event,
+        quest: u8,
+        part: u8,
+        answer: &Answer,
+    ) -> Result<Verdict, Box<dyn Error>> {
+        let url = format!(
+            "{}/api/event/{}/quest/{quest}/part/{part}/answer",
+            self.site_url,
+            event.id()
+        );
+        let result = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie)
+            .send_json(serde_json::json!({ "answer": answer.to_string() }));
+
+        let response: SubmitResponse = match result {
+            Ok(response) => response
+                .into_json()
+                .map_err(|err| format!("invalid response from {url}: {err}"))?,
+            Err(ureq::Error::Status(status, response)) if TOO_EARLY_STATUSES.contains(&status) => {
+                return Ok(Verdict::TooEarly(response.into_string()?));
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        if response.correct {
+            Ok(Verdict::Correct)
+        } else {
+            Ok(Verdict::Wrong {
+                length_correct: response.length_correct,
+                first_correct: response.first_correct,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        env,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        process,
+        sync::atomic::{AtomicUsize, Ordering},
+        thread::{self, JoinHandle},
+    };
+
+    /// Serve a single request with the given status and body, returning the
+    /// base URL of the server and the request it received
+    fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+        (base_url, server)
+    }
+
+    fn submit(status: &'static str, body: &'static str) -> (Result<Verdict, String>, String) {
+        let (base_url, server) = serve_once(status, body);
+        // Tests run in parallel, each with its own cookie file
+        static NB_CLIENTS: AtomicUsize = AtomicUsize::new(0);
+        let cookie_file = env::temp_dir().join(format!(
+            "everybody_codes_cookie_{}_{}",
+            process::id(),
+            NB_CLIENTS.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&cookie_file, "secret\n").unwrap();
+        let client = Client::new(&cookie_file, Some(&base_url)).unwrap();
+        fs::remove_file(&cookie_file).unwrap();
+
+        let verdict = client
+            .submit_answer(Event::Year(2024), 1, 2, &Answer::from("ABC"))
+            .map_err(|err| err.to_string());
+        (verdict, server.join().unwrap())
+    }
+
+    #[test]
+    fn submits_to_the_answer_endpoint() {
+        let (verdict, request) = submit("200 OK", r#"{"correct":true}"#);
+
+        assert!(matches!(verdict, Ok(Verdict::Correct)));
+        assert!(request.starts_with("POST /api/event/2024/quest/1/part/2/answer HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: everybody-codes=secret\r\n"));
+        assert!(request.ends_with(r#"{"answer":"ABC"}"#));
+    }
+
+    #[test]
+    fn parses_wrong_answers_with_their_hints() {
+        let (verdict, _) = submit(
+            "200 OK",
+            r#"{"correct":false,"lengthCorrect":true,"firstCorrect":false}"#,
+        );
+
+        assert!(matches!(
+            verdict,
+            Ok(Verdict::Wrong {
+                length_correct: true,
+                first_correct: false
+            })
+        ));
+    }
+
+    #[test]
+    fn reports_refusals_as_too_early() {
+        let (verdict, _) = submit("423 Locked", "Part is locked");
+
+        assert!(matches!(verdict, Ok(Verdict::TooEarly(message)) if message == "Part is locked"));
+    }
+
+    #[test]
+    fn fails_on_other_errors() {
+        let (verdict, _) = submit("500 Internal Server Error", "");
+
+        assert!(verdict.is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use crate::args::{Color, Format};
+use everybody_codes::Event;
+use serde::Deserialize;
+use std::{
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// File read from the working directory when no config file is given
+pub const DEFAULT_CONFIG_FILE: &str = "ec.toml";
+
+/// Defaults of the runner, stored in `ec.toml`. Command-line flags and
+/// environment variables take precedence over them
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Directory containing the `input` tree
+    pub root: Option<PathBuf>,
+    /// File containing the session cookie of the site
+    pub cookie_file: Option<PathBuf>,
+    pub format: Option<Format>,
+    pub color: Option<Color>,
+    /// Event used when none is given
+    pub event: Option<Event>,
+    /// Parts run by default, the latest one with an input for a single run
+    pub parts: Option<Vec<u8>>,
+}
+
+impl Config {
+    /// Read a config file, falling back to the defaults if no file was given
+    /// and `ec.toml` does not exist. Relative paths are resolved from the
+    /// directory of the file
+    pub fn load(path: Option<&Path>) -> Result<Self, Box<dyn Error>> {
+        let file = path.unwrap_or(Path::new(DEFAULT_CONFIG_FILE));
+        let content = match fs::read_to_string(file) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound && path.is_none() => {
+                return Ok(Self::default());
+            }
+            Err(err) => return Err(format!("cannot read {}: {err}", file.display()).into()),
+        };
+        let mut config: Self = toml::from_str(&content)
+            .map_err(|err| format!("invalid config {}: {err}", file.display()))?;
+
+        if let Some(part) = config
+            .parts
+            .iter()
+            .flatten()
+            .find(|part| !(1..=3).contains(*part))
+        {
+            return Err(format!("invalid part {part} in {}", file.display()).into());
+        }
+
+        let dir = file.parent().unwrap_or(Path::new(""));
+        config.root = config.root.map(|root| dir.join(root));
+        config.cookie_file = config.cookie_file.map(|cookie_file| dir.join(cookie_file));
+        Ok(config)
+    }
+
+    pub fn parts(&self) -> &[u8] {
+        self.parts.as_deref().unwrap_or(&[1, 2, 3])
+    }
+}
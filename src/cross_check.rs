@@ -0,0 +1,89 @@
+use crate::{args::RunArgs, report, report::RunRecord};
+use everybody_codes::variants;
+use std::fmt;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Status {
+    Agree,
+    Differ,
+    Single,
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status = match self {
+            Self::Agree => "AGREE",
+            Self::Differ => "DIFFER",
+            Self::Single => "SINGLE",
+            Self::Error => "ERROR",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// Run every variant of the selected parts and compare their answers,
+/// returning whether the variants of each part agree
+pub fn cross_check(runs: &[RunArgs]) -> bool {
+    if runs.is_empty() {
+        println!("No registered solution has an input file");
+        return true;
+    }
+
+    let mut statuses = Vec::new();
+    let mut rows = Vec::new();
+    for args in runs {
+        let input = args.read_input();
+        let records: Vec<RunRecord> = variants(args.event, args.day)
+            .into_iter()
+            .map(|variant| {
+                let args = RunArgs {
+                    variant,
+                    ..args.clone()
+                };
+                RunRecord::run_on(&args, input.as_deref())
+            })
+            .collect();
+
+        let answers: Vec<_> = records.iter().map(|record| &record.answer).collect();
+        let status = if answers.iter().any(|answer| answer.is_none()) {
+            Status::Error
+        } else if records.len() == 1 {
+            Status::Single
+        } else if answers.iter().all(|&answer| answer == answers[0]) {
+            Status::Agree
+        } else {
+            Status::Differ
+        };
+
+        for record in &records {
+            let mut row = report::run_label(args);
+            row.extend([
+                record.variant.to_string(),
+                record.outcome(),
+                format!("{:.2?}", record.duration),
+                status.to_string(),
+            ]);
+            rows.push(row);
+        }
+        statuses.push(status);
+    }
+
+    report::print_table(
+        &[
+            "Event", "Day", "Part", "Variant", "Answer", "Time", "Status",
+        ],
+        &rows,
+    );
+
+    let count = |status| statuses.iter().filter(|&&s| s == status).count();
+    println!(
+        "\n{} agree, {} differ, {} with a single variant, {} errors",
+        count(Status::Agree),
+        count(Status::Differ),
+        count(Status::Single),
+        count(Status::Error),
+    );
+
+    count(Status::Differ) + count(Status::Error) == 0
+}
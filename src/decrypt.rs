@@ -0,0 +1,128 @@
+use crate::{answers::Keys, args::get_default_input, fetch::get_notes_file};
+use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit};
+use everybody_codes::Event;
+use std::{collections::BTreeMap, error::Error, fs, path::Path};
+
+type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
+
+/// Decrypt the notes of a part, given as an hexadecimal string. The key is
+/// used both as the AES-256 key and, for its first 16 bytes, as the IV
+pub fn decrypt_notes(notes: &str, key: &str) -> Result<String, Box<dyn Error>> {
+    let key = key.as_bytes();
+    if key.len() != 32 {
+        return Err(format!("key is {} bytes long instead of 32", key.len()).into());
+    }
+
+    let plaintext = Aes256CbcDec::new(key.into(), key[..16].into())
+        .decrypt_padded_vec_mut::<Pkcs7>(&decode_hex(notes.trim())?)
+        .map_err(|_| "wrong key or corrupted notes")?;
+    Ok(String::from_utf8(plaintext)?)
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    if !hex.is_ascii() || !hex.len().is_multiple_of(2) {
+        return Err("notes are not an hexadecimal string".into());
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex[i..i + 2], 16)
+                .map_err(|_| "notes are not an hexadecimal string".into())
+        })
+        .collect()
+}
+
+/// Write the input of every part of a day whose key is known, from the notes
+/// saved by `fetch`, and return these parts
+pub fn decrypt_day(root: &Path, event: Event, day: u8) -> Result<Vec<u8>, Box<dyn Error>> {
+    let notes_file = get_notes_file(root, event, day);
+    let notes: BTreeMap<String, String> = serde_json::from_str(
+        &fs::read_to_string(&notes_file)
+            .map_err(|err| format!("cannot read {}: {err}", notes_file.display()))?,
+    )?;
+    let keys = Keys::load(root, event, day)?;
+
+    let mut parts = Vec::new();
+    for part in 1..=3 {
+        let (Some(key), Some(encrypted)) = (keys.get(part), notes.get(&part.to_string())) else {
+            continue;
+        };
+        let input = decrypt_notes(encrypted, key).map_err(|err| format!("part {part}: {err}"))?;
+        fs::write(get_default_input(root, event, day, part), input)?;
+        parts.push(part);
+    }
+    Ok(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::get_day_file, args::get_day_directory};
+    use std::{env, process};
+
+    const NOTES: &str = include_str!("../fixtures/decrypt/notes.json");
+    const KEYS: &str = include_str!("../fixtures/decrypt/keys.toml");
+    const PART1: &str = include_str!("../fixtures/decrypt/part1.txt");
+    const PART2: &str = include_str!("../fixtures/decrypt/part2.txt");
+    const PART3: &str = include_str!("../fixtures/decrypt/part3.txt");
+    /// Key of the third part, still locked in the keys fixture
+    const KEY3: &str = "pD-7rG=kS2!uA9;fE4jQ~xO6zL@nV1tB";
+
+    fn fixture_notes() -> BTreeMap<String, String> {
+        serde_json::from_str(NOTES).unwrap()
+    }
+
+    #[test]
+    fn decrypts_notes_with_their_key() {
+        let notes = fixture_notes();
+        let keys: Keys = toml::from_str(KEYS).unwrap();
+
+        assert_eq!(
+            decrypt_notes(&notes["1"], keys.get(1).unwrap()).unwrap(),
+            PART1
+        );
+        assert_eq!(
+            decrypt_notes(&notes["2"], keys.get(2).unwrap()).unwrap(),
+            PART2
+        );
+    }
+
+    #[test]
+    fn decrypts_multiline_notes() {
+        let notes = fixture_notes();
+
+        assert_eq!(decrypt_notes(&notes["3"], KEY3).unwrap(), PART3);
+    }
+
+    #[test]
+    fn rejects_wrong_keys() {
+        let notes = fixture_notes();
+        let keys: Keys = toml::from_str(KEYS).unwrap();
+
+        assert!(decrypt_notes(&notes["1"], keys.get(2).unwrap()).is_err());
+        assert!(decrypt_notes(&notes["1"], "too short").is_err());
+        assert!(decrypt_notes("not hexadecimal", keys.get(1).unwrap()).is_err());
+    }
+
+    #[test]
+    fn writes_inputs_of_unlocked_parts() {
+        let root = env::temp_dir().join(format!("everybody_codes_decrypt_{}", process::id()));
+        fs::create_dir_all(get_day_directory(&root, Event::Year(2024), 1)).unwrap();
+        fs::write(get_notes_file(&root, Event::Year(2024), 1), NOTES).unwrap();
+        fs::write(get_day_file(&root, Event::Year(2024), 1, "keys.toml"), KEYS).unwrap();
+
+        let parts = decrypt_day(&root, Event::Year(2024), 1);
+        let inputs = (1..=3)
+            .map(|part| {
+                fs::read_to_string(get_default_input(&root, Event::Year(2024), 1, part)).ok()
+            })
+            .collect::<Vec<_>>();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(parts.unwrap(), [1, 2]);
+        assert_eq!(
+            inputs,
+            [Some(PART1.to_string()), Some(PART2.to_string()), None]
+        );
+    }
+}
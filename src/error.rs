@@ -0,0 +1,189 @@
+use crate::Event;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Error raised by a solution that cannot make sense of its input
+#[derive(Debug)]
+pub struct InputError {
+    line: Option<usize>,
+    content: Option<String>,
+    message: String,
+}
+
+impl InputError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            content: None,
+            message: message.into(),
+        }
+    }
+
+    /// Error on the line at `index` (starting from 0) of the input
+    pub fn at_line(index: usize, message: impl Into<String>) -> Self {
+        Self {
+            line: Some(index),
+            ..Self::new(message)
+        }
+    }
+
+    /// Line number (starting from 1) of the offending input line, if known
+    pub fn line(&self) -> Option<usize> {
+        self.line.map(|index| index + 1)
+    }
+
+    fn with_content(mut self, input: &str) -> Self {
+        self.content = self
+            .line
+            .and_then(|index| input.lines().nth(index))
+            .map(str::to_string);
+        self
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.line(), &self.content) {
+            (Some(line), Some(content)) => write!(f, "line {line} `{content}`: {}", self.message),
+            (Some(line), None) => write!(f, "line {line}: {}", self.message),
+            (None, _) => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+/// Parse a value found on the line at `index` of the input
+pub fn parse_at<T: FromStr>(index: usize, text: &str) -> Result<T, InputError> {
+    text.parse()
+        .map_err(|_| InputError::at_line(index, format!("invalid value `{text}`")))
+}
+
+/// Error of a registered solution, naming the day and part it comes from
+#[derive(Debug)]
+pub struct SolveError {
+    pub event: Event,
+    pub day: u8,
+    pub part: u8,
+    pub error: InputError,
+}
+
+impl SolveError {
+    pub(crate) fn new(event: Event, day: u8, part: u8, error: InputError, input: &str) -> Self {
+        Self {
+            event,
+            day,
+            part,
+            error: error.with_content(input),
+        }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}/{:02}/{}: {}",
+            self.event, self.day, self.part, self.error
+        )
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Lookup of a day, or of a variant of a day, missing from the registry
+#[derive(Debug)]
+pub enum NotRegistered {
+    /// The day has no solution, listing the registered days closest to it
+    Day {
+        event: Event,
+        day: u8,
+        nearest: Vec<(Event, u8)>,
+    },
+    /// The day has no such variant, listing the ones it has
+    Variant {
+        event: Event,
+        day: u8,
+        variant: String,
+        variants: Vec<&'static str>,
+    },
+}
+
+impl Display for NotRegistered {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Day {
+                event,
+                day,
+                nearest,
+            } => {
+                write!(f, "no solution registered for {event} day {day:02}")?;
+                let nearest: Vec<String> = nearest
+                    .iter()
+                    .map(|(event, day)| format!("{event} day {day:02}"))
+                    .collect();
+                if !nearest.is_empty() {
+                    write!(f, ", nearest registered days: {}", nearest.join(", "))?;
+                }
+                Ok(())
+            }
+            Self::Variant {
+                event,
+                day,
+                variant,
+                variants,
+            } => write!(
+                f,
+                "no variant `{variant}` registered for {event} day {day:02}, expected one of: {}",
+                variants.join(", ")
+            ),
+        }
+    }
+}
+
+impl Error for NotRegistered {}
+
+/// Error of a run of a solution: either it is not registered, or it rejected
+/// its input
+#[derive(Debug)]
+pub enum RunError {
+    NotRegistered(NotRegistered),
+    Solve(SolveError),
+}
+
+impl From<NotRegistered> for RunError {
+    fn from(err: NotRegistered) -> Self {
+        Self::NotRegistered(err)
+    }
+}
+
+impl From<SolveError> for RunError {
+    fn from(err: SolveError) -> Self {
+        Self::Solve(err)
+    }
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotRegistered(err) => err.fmt(f),
+            Self::Solve(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for RunError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NotRegistered(err) => err.source(),
+            Self::Solve(err) => err.source(),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Event of the site: a yearly event of 20 quests, or a standalone story of
+/// 3 quests. Yearly events are shown as their year, stories as `storyN`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Event {
+    Year(u32),
+    Story(u8),
+}
+
+impl Event {
+    /// Number used by the site for the event
+    pub fn id(self) -> u32 {
+        match self {
+            Self::Year(year) => year,
+            Self::Story(story) => story.into(),
+        }
+    }
+
+    pub fn nb_quests(self) -> u8 {
+        match self {
+            Self::Year(_) => 20,
+            Self::Story(_) => 3,
+        }
+    }
+
+    /// Name of the module of the event, also used for its input directory
+    pub fn module_name(self) -> String {
+        match self {
+            Self::Year(year) => format!("year_{year}"),
+            Self::Story(story) => format!("story_{story:02}"),
+        }
+    }
+
+    /// Name of the module of a quest of the event
+    pub fn quest_name(self, quest: u8) -> String {
+        match self {
+            Self::Year(_) => format!("day{quest:02}"),
+            Self::Story(_) => format!("quest{quest:02}"),
+        }
+    }
+
+    /// Inverse of `module_name`
+    pub fn from_module_name(name: &str) -> Option<Self> {
+        if let Some(year) = name.strip_prefix("year_") {
+            year.parse().ok().map(Self::Year)
+        } else {
+            name.strip_prefix("story_")?.parse().ok().map(Self::Story)
+        }
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Year(year) => write!(f, "{year}"),
+            Self::Story(story) => write!(f, "story{story}"),
+        }
+    }
+}
+
+impl FromStr for Event {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let event = match s.strip_prefix("story") {
+            Some(story) => story
+                .parse()
+                .ok()
+                .filter(|&story| story > 0)
+                .map(Self::Story),
+            None => s.parse().ok().map(Self::Year),
+        };
+        event.ok_or_else(|| format!("invalid event `{s}`, expected a year or `storyN`"))
+    }
+}
+
+/// Years are stored as numbers, stories as their name
+impl Serialize for Event {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Year(year) => serializer.serialize_u32(*year),
+            Self::Story(_) => serializer.collect_str(self),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Year(u32),
+            Name(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Year(year) => Ok(Self::Year(year)),
+            Raw::Name(name) => name.parse().map_err(serde::de::Error::custom),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{args::RunArgs, report::RunRecord};
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Number of threads used when not given: one per available core
+pub fn default_jobs() -> NonZeroUsize {
+    thread::available_parallelism().unwrap_or(NonZeroUsize::MIN)
+}
+
+/// Run the solutions on up to `jobs` threads, each taking the next pending
+/// run. Returns the records in the order of the runs, along with the
+/// wall-clock time of the whole execution
+pub fn run_all(runs: &[RunArgs], jobs: NonZeroUsize) -> (Vec<RunRecord>, Duration) {
+    let start = Instant::now();
+    let next = AtomicUsize::new(0);
+    let nb_threads = jobs.get().min(runs.len());
+
+    let mut records: Vec<(usize, RunRecord)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..nb_threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut records = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(args) = runs.get(index) else {
+                            return records;
+                        };
+                        records.push((index, RunRecord::run(args)));
+                    }
+                })
+            })
+            .collect();
+
+        // Panics of the solutions are caught by `RunRecord::run`
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    });
+
+    records.sort_by_key(|&(index, _)| index);
+    let records = records.into_iter().map(|(_, record)| record).collect();
+    (records, start.elapsed())
+}
@@ -0,0 +1,36 @@
+use crate::{answers::Answers, args::get_example_input, html, read::get_statement_file};
+use everybody_codes::Event;
+use std::{error::Error, fs, path::Path};
+
+/// Write the example notes of every part whose statement is cached, and record
+/// their expected answers. Existing examples are kept unless `force` is set
+pub fn extract(root: &Path, event: Event, day: u8, force: bool) -> Result<(), Box<dyn Error>> {
+    let mut answers = Answers::load(root, event, day)?;
+    let mut nb_statements = 0;
+    for part in 1..=3 {
+        let Ok(statement) = fs::read_to_string(get_statement_file(root, event, day, part)) else {
+            continue;
+        };
+        nb_statements += 1;
+
+        let Some((notes, answer)) = html::get_example(&statement) else {
+            println!("No example found in the statement of part {part}");
+            continue;
+        };
+        let example_file = get_example_input(root, event, day, part);
+        if example_file.exists() && !force {
+            println!("Kept existing {}", example_file.display());
+            continue;
+        }
+        fs::write(&example_file, notes)?;
+        println!("Wrote {} (answer: {answer})", example_file.display());
+        answers.set_example(part, answer.parse()?);
+    }
+
+    if nb_statements == 0 {
+        return Err(
+            format!("no cached statement for {event} day {day:02}, run `read` first").into(),
+        );
+    }
+    answers.save(root, event, day)
+}
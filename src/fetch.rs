@@ -0,0 +1,43 @@
+use crate::{answers::get_day_file, args::get_default_input, client::Client, decrypt};
+use everybody_codes::Event;
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub fn get_notes_file(root: &Path, event: Event, day: u8) -> PathBuf {
+    get_day_file(root, event, day, "notes.json")
+}
+
+/// Download the encrypted notes of a day along with the keys unlocked so far,
+/// and decrypt the inputs of the unlocked parts
+pub fn fetch(client: &Client, root: &Path, event: Event, day: u8) -> Result<(), Box<dyn Error>> {
+    let seed = client.get_seed()?;
+    let notes = client.get_encrypted_notes(event, day, seed)?;
+    let keys = client.get_keys(event, day)?;
+
+    let notes_file = get_notes_file(root, event, day);
+    fs::create_dir_all(notes_file.parent().unwrap())?;
+    fs::write(&notes_file, serde_json::to_string_pretty(&notes)?)?;
+    println!("Saved encrypted notes to {}", notes_file.display());
+
+    keys.save(root, event, day)?;
+    let unlocked: Vec<String> = (1..=3)
+        .filter(|&part| keys.get(part).is_some())
+        .map(|part| part.to_string())
+        .collect();
+    if unlocked.is_empty() {
+        println!("No part unlocked yet");
+        return Ok(());
+    }
+    println!("Keys unlocked for parts {}", unlocked.join(", "));
+
+    for part in decrypt::decrypt_day(root, event, day)? {
+        println!(
+            "Wrote {}",
+            get_default_input(root, event, day, part).display()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,458 @@
+/// Width at which paragraphs are wrapped
+const WIDTH: usize = 80;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Style {
+    Bold,
+    Italic,
+    Code,
+}
+
+impl Style {
+    fn marker(self, color: bool, open: bool) -> &'static str {
+        match (self, color, open) {
+            (Self::Bold, true, true) => "\x1b[1m",
+            (Self::Bold, true, false) => "\x1b[22m",
+            (Self::Italic, true, true) => "\x1b[3m",
+            (Self::Italic, true, false) => "\x1b[23m",
+            (Self::Code, true, true) => "\x1b[36m",
+            (Self::Code, true, false) => "\x1b[39m",
+            (Self::Bold, false, _) => "**",
+            (Self::Italic, false, _) => "*",
+            (Self::Code, false, _) => "`",
+        }
+    }
+
+    fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "b" | "strong" => Some(Self::Bold),
+            "i" | "em" => Some(Self::Italic),
+            "code" => Some(Self::Code),
+            _ => None,
+        }
+    }
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Open(String),
+    Close(String),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        if rest[start..].starts_with("<!--") {
+            let end = rest[start..]
+                .find("-->")
+                .map_or(rest.len(), |end| start + end + 3);
+            rest = &rest[end..];
+            continue;
+        }
+
+        let Some(end) = rest[start..].find('>').map(|end| start + end) else {
+            tokens.push(Token::Text(&rest[start..]));
+            break;
+        };
+        let tag = &rest[start + 1..end];
+        let name: String = tag
+            .trim_start_matches('/')
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        if tag.starts_with('/') {
+            tokens.push(Token::Close(name));
+        } else {
+            tokens.push(Token::Open(name.clone()));
+            if tag.ends_with('/') {
+                tokens.push(Token::Close(name));
+            }
+        }
+        rest = &rest[end + 1..];
+    }
+    tokens
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..].find(';').map(|end| &rest[1..end + 1]);
+        let c = match entity {
+            Some("lt") => Some('<'),
+            Some("gt") => Some('>'),
+            Some("amp") => Some('&'),
+            Some("quot") => Some('"'),
+            Some("apos") => Some('\''),
+            Some("nbsp") => Some(' '),
+            Some(code) if code.starts_with("#x") || code.starts_with("#X") => {
+                u32::from_str_radix(&code[2..], 16)
+                    .ok()
+                    .and_then(char::from_u32)
+            }
+            Some(code) if code.starts_with('#') => code[1..].parse().ok().and_then(char::from_u32),
+            _ => None,
+        };
+        match (c, entity) {
+            (Some(c), Some(entity)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn is_bold(tag: &str) -> bool {
+    matches!(tag, "b" | "strong")
+}
+
+fn is_heading(tag: &str) -> bool {
+    matches!(tag, "h1" | "h2" | "h3" | "h4" | "h5" | "h6")
+}
+
+/// Number of characters shown by the terminal, skipping color escapes
+fn visible_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut in_escape = false;
+    for c in text.chars() {
+        match c {
+            '\x1b' => in_escape = true,
+            'm' if in_escape => in_escape = false,
+            _ if in_escape => (),
+            _ => width += 1,
+        }
+    }
+    width
+}
+
+struct Renderer {
+    color: bool,
+    output: String,
+    paragraph: String,
+    /// Prefix of the first line of the paragraph, e.g. a list bullet
+    prefix: String,
+    indent: usize,
+    lists: Vec<Option<u32>>,
+    styles: Vec<Style>,
+    in_pre: bool,
+    skip: usize,
+}
+
+impl Renderer {
+    fn new(color: bool) -> Self {
+        Self {
+            color,
+            output: String::new(),
+            paragraph: String::new(),
+            prefix: String::new(),
+            indent: 0,
+            lists: Vec::new(),
+            styles: Vec::new(),
+            in_pre: false,
+            skip: 0,
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        let text = decode_entities(text);
+        if self.in_pre {
+            self.paragraph.push_str(&text);
+            return;
+        }
+        for (i, word) in text.split(|c: char| c.is_ascii_whitespace()).enumerate() {
+            if i > 0 && !self.paragraph.ends_with(' ') {
+                self.paragraph.push(' ');
+            }
+            self.paragraph.push_str(word);
+        }
+    }
+
+    fn open_style(&mut self, style: Style) {
+        self.paragraph.push_str(style.marker(self.color, true));
+        self.styles.push(style);
+    }
+
+    fn close_style(&mut self, style: Style) {
+        if let Some(pos) = self.styles.iter().rposition(|&s| s == style) {
+            self.styles.remove(pos);
+            self.paragraph.push_str(style.marker(self.color, false));
+        }
+    }
+
+    /// Separate blocks with an empty line
+    fn start_block(&mut self) {
+        self.flush();
+        if !self.output.is_empty() && !self.output.ends_with("\n\n") {
+            self.output.push('\n');
+        }
+    }
+
+    fn flush(&mut self) {
+        if self.in_pre {
+            let indent = " ".repeat(self.indent + 4);
+            for line in self.paragraph.trim_matches('\n').lines() {
+                self.output.push_str(&indent);
+                self.output.push_str(line);
+                self.output.push('\n');
+            }
+        } else if !self.paragraph.trim().is_empty() {
+            self.wrap_paragraph();
+        }
+        self.paragraph.clear();
+        self.prefix.clear();
+    }
+
+    fn wrap_paragraph(&mut self) {
+        let indent = " ".repeat(self.indent);
+        let mut line = format!("{indent}{}", self.prefix);
+        let mut line_is_empty = true;
+        for word in self.paragraph.split(' ').filter(|word| !word.is_empty()) {
+            let width = visible_width(word);
+            if !line_is_empty && visible_width(&line) + 1 + width > WIDTH {
+                self.output.push_str(line.trim_end());
+                self.output.push('\n');
+                line = indent.clone() + &" ".repeat(visible_width(&self.prefix));
+                line_is_empty = true;
+            }
+            if !line_is_empty && width > 0 {
+                line.push(' ');
+            }
+            line.push_str(word);
+            line_is_empty &= width == 0;
+        }
+        self.output.push_str(line.trim_end());
+        self.output.push('\n');
+    }
+
+    fn open(&mut self, tag: &str) {
+        if self.skip > 0 {
+            self.skip += matches!(tag, "script" | "style") as usize;
+            return;
+        }
+        match tag {
+            "script" | "style" => self.skip = 1,
+            "p" | "div" | "table" | "blockquote" => self.start_block(),
+            "tr" => self.flush(),
+            "br" if self.in_pre => self.paragraph.push('\n'),
+            "br" => self.flush(),
+            "pre" => {
+                self.start_block();
+                self.in_pre = true;
+            }
+            "ul" | "ol" => {
+                self.start_block();
+                self.lists.push((tag == "ol").then_some(0));
+            }
+            "li" => {
+                self.flush();
+                self.indent = 2 * self.lists.len().saturating_sub(1);
+                self.prefix = match self.lists.last_mut() {
+                    Some(Some(count)) => {
+                        *count += 1;
+                        format!("{count}. ")
+                    }
+                    _ => String::from("- "),
+                };
+            }
+            _ if is_heading(tag) => {
+                self.start_block();
+                if self.color {
+                    self.open_style(Style::Bold);
+                } else {
+                    let level = tag[1..].parse().unwrap();
+                    self.paragraph.push_str(&"#".repeat(level));
+                    self.paragraph.push(' ');
+                }
+            }
+            _ => {
+                if let Some(style) = Style::from_tag(tag).filter(|_| !self.in_pre) {
+                    self.open_style(style);
+                }
+            }
+        }
+    }
+
+    fn close(&mut self, tag: &str) {
+        if self.skip > 0 {
+            self.skip -= matches!(tag, "script" | "style") as usize;
+            return;
+        }
+        match tag {
+            "p" | "div" | "table" | "blockquote" | "li" => self.flush(),
+            "tr" | "td" | "th" => self.paragraph.push(' '),
+            "pre" => {
+                self.flush();
+                self.in_pre = false;
+                self.start_block();
+            }
+            "ul" | "ol" => {
+                self.flush();
+                self.lists.pop();
+                self.indent = 2 * self.lists.len().saturating_sub(1);
+            }
+            _ if is_heading(tag) => {
+                self.close_style(Style::Bold);
+                self.start_block();
+            }
+            _ => {
+                if let Some(style) = Style::from_tag(tag).filter(|_| !self.in_pre) {
+                    self.close_style(style);
+                }
+            }
+        }
+    }
+}
+
+/// Render an HTML quest statement as terminal text, wrapping paragraphs and
+/// keeping code blocks as is. Emphasis and code use colors if `color` is set,
+/// Markdown-like markers otherwise
+pub fn render(html: &str, color: bool) -> String {
+    let mut renderer = Renderer::new(color);
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) if renderer.skip == 0 => renderer.push_text(text),
+            Token::Text(_) => (),
+            Token::Open(tag) => renderer.open(&tag),
+            Token::Close(tag) => renderer.close(&tag),
+        }
+    }
+    renderer.flush();
+    renderer.output.trim_end().to_string() + "\n"
+}
+
+/// Text of the first heading of a statement
+pub fn get_title(html: &str) -> Option<String> {
+    let mut title: Option<String> = None;
+    for token in tokenize(html) {
+        match (token, &mut title) {
+            (Token::Open(tag), None) if is_heading(&tag) => {
+                title = Some(String::new());
+            }
+            (Token::Text(text), Some(title)) => title.push_str(&decode_entities(text)),
+            (Token::Close(tag), Some(title)) if is_heading(&tag) => {
+                return Some(title.split_whitespace().collect::<Vec<_>>().join(" "));
+            }
+            _ => (),
+        }
+    }
+    None
+}
+
+/// Notes of the first example of a statement, along with its answer: the
+/// first bold text following the notes
+pub fn get_example(html: &str) -> Option<(String, String)> {
+    let mut notes: Option<String> = None;
+    let mut in_notes = false;
+    let mut answer: Option<String> = None;
+    for token in tokenize(html) {
+        match token {
+            Token::Open(tag) if tag == "pre" && notes.is_none() => {
+                notes = Some(String::new());
+                in_notes = true;
+            }
+            Token::Close(tag) if tag == "pre" => in_notes = false,
+            Token::Open(tag) if is_bold(&tag) && notes.is_some() && !in_notes => {
+                answer = Some(String::new());
+            }
+            Token::Close(tag) if is_bold(&tag) && answer.is_some() => {
+                if answer.as_ref()?.trim().is_empty() {
+                    answer = None;
+                } else {
+                    break;
+                }
+            }
+            Token::Text(text) if in_notes => notes.as_mut()?.push_str(&decode_entities(text)),
+            Token::Text(text) if answer.is_some() => {
+                answer.as_mut()?.push_str(&decode_entities(text))
+            }
+            _ => (),
+        }
+    }
+
+    let notes = notes?.trim_matches('\n').to_string();
+    let answer = answer?.trim().to_string();
+    (!notes.is_empty() && !answer.is_empty()).then_some((notes, answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATEMENT: &str = include_str!("../fixtures/html/statement.html");
+
+    #[test]
+    fn finds_the_title() {
+        assert_eq!(
+            get_title(STATEMENT).as_deref(),
+            Some("Quest 5: Pseudo-Random Clap Dance")
+        );
+        assert_eq!(get_title("<p>No heading</p>"), None);
+    }
+
+    #[test]
+    fn extracts_the_first_example_and_its_answer() {
+        let (notes, answer) = get_example(STATEMENT).unwrap();
+
+        assert_eq!(notes, "2 3 4 5\n3 4 5 2\n4 5 2 3");
+        assert_eq!(answer, "3345");
+    }
+
+    #[test]
+    fn ignores_bold_text_before_the_notes_and_empty_bold_text() {
+        let html = "<p><b>Note</b>:</p><pre>1 2</pre><p><b> </b>Total: <strong>3</strong></p>";
+
+        assert_eq!(
+            get_example(html),
+            Some((String::from("1 2"), String::from("3")))
+        );
+        assert_eq!(get_example("<pre>1 2</pre><p>No answer</p>"), None);
+        assert_eq!(get_example("<p>No notes, <b>3</b></p>"), None);
+    }
+
+    #[test]
+    fn renders_without_color() {
+        let text = render(STATEMENT, false);
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "## Quest 5: Pseudo-Random Clap Dance");
+        assert_eq!(
+            lines[2],
+            "The knights & squires form *columns* of dancers, read from the notes below:"
+        );
+        assert_eq!(&lines[4..7], ["    2 3 4 5", "    3 4 5 2", "    4 5 2 3"]);
+        assert!(text.contains("- The clapper claps `N` times."));
+        assert!(text.contains("  1. left to right,\n  2. then back to the first one."));
+        assert!(text.contains("first digits\n<> of each column, AB…"));
+        assert!(!text.contains("not the answer"));
+        assert!(lines.iter().all(|line| line.chars().count() <= WIDTH));
+    }
+
+    #[test]
+    fn renders_with_color() {
+        let text = render("<h1>Title</h1><p>A <b>bold</b> <code>x</code></p>", true);
+
+        assert_eq!(
+            text,
+            "\x1b[1mTitle\x1b[22m\n\nA \x1b[1mbold\x1b[22m \x1b[36mx\x1b[39m\n"
+        );
+    }
+}
@@ -1,39 +1,120 @@
-mod args;
+mod answer;
+mod error;
+mod event;
 
-use std::{fmt::Display, iter};
+use std::{collections::BTreeMap, sync::OnceLock};
 
-pub use args::RunArgs;
+pub use answer::Answer;
+pub use error::{parse_at, InputError, NotRegistered, RunError, SolveError};
+pub use event::Event;
+
+/// Name of the variant of a day implemented by its `solve` function
+pub const DEFAULT_VARIANT: &str = "default";
+
+type SolveFn = fn(&str, u8) -> Result<Answer, InputError>;
 
 struct Solution {
-    year: u32,
+    event: Event,
     day: u8,
-    run: fn(&RunArgs) -> Box<dyn Display>,
+    variant: &'static str,
+    solve: SolveFn,
 }
 
-macro_rules! load_year {
-    ($year:ident: $($day:ident),*) => {
-        pub mod $year {
-            $(pub mod $day;)*
-        }
-        fn $year() -> Vec<Solution> {
-            vec![$({
-                let year = stringify!($year).strip_prefix("year_").unwrap().parse().unwrap();
-                let day = stringify!($day).strip_prefix("day").unwrap().parse().unwrap();
-                let run = |args: &RunArgs| Box::new($year::$day::run(args)) as Box<dyn Display>;
-
-                Solution { year, day, run }
-            },)*]
-        }
+/// Solution of a day found by the build script, from the names of its modules
+fn solution(event: &str, day: &str, variant: &'static str, solve: SolveFn) -> Solution {
+    Solution {
+        event: Event::from_module_name(event).unwrap(),
+        day: day.trim_start_matches(char::is_alphabetic).parse().unwrap(),
+        variant,
+        solve,
     }
 }
 
-load_year!(year_2024: day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20);
+// Day modules and their `solutions()`, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+/// Variants of every registered day, starting with the default one
+fn registry() -> &'static BTreeMap<(Event, u8), Vec<Solution>> {
+    static REGISTRY: OnceLock<BTreeMap<(Event, u8), Vec<Solution>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut registry: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for solution in solutions() {
+            registry
+                .entry((solution.event, solution.day))
+                .or_default()
+                .push(solution);
+        }
+        registry
+    })
+}
+
+pub fn registered_days() -> Vec<(Event, u8)> {
+    registry().keys().copied().collect()
+}
+
+/// Names of the variants of a registered day, starting with the default one
+pub fn variants(event: Event, day: u8) -> Vec<&'static str> {
+    registry()
+        .get(&(event, day))
+        .into_iter()
+        .flatten()
+        .map(|solution| solution.variant)
+        .collect()
+}
+
+/// Registered days closest to a day, those of its event first
+fn nearest_days(event: Event, day: u8) -> Vec<(Event, u8)> {
+    let mut days = registered_days();
+    days.sort_by_key(|&(solution_event, solution_day)| {
+        (solution_event != event, solution_day.abs_diff(day))
+    });
+    days.truncate(3);
+    days.sort();
+    days
+}
+
+fn find_solution(event: Event, day: u8, variant: &str) -> Result<&'static Solution, NotRegistered> {
+    let Some(solutions) = registry().get(&(event, day)) else {
+        return Err(NotRegistered::Day {
+            event,
+            day,
+            nearest: nearest_days(event, day),
+        });
+    };
+    solutions
+        .iter()
+        .find(|solution| solution.variant == variant)
+        .ok_or_else(|| NotRegistered::Variant {
+            event,
+            day,
+            variant: variant.to_string(),
+            variants: variants(event, day),
+        })
+}
 
-pub fn run_solution(args: &RunArgs) -> Box<dyn Display> {
-    let run_fn: Vec<Solution> = iter::empty()
-        .chain(year_2024())
-        .filter(|solution| args.year == solution.year && args.day == solution.day)
-        .collect();
+/// Check that a variant of a day is registered, returning its name
+pub fn find_variant(event: Event, day: u8, variant: &str) -> Result<&'static str, NotRegistered> {
+    find_solution(event, day, variant).map(|solution| solution.variant)
+}
+
+/// Solve a part of a registered day from its input text
+pub fn solve(event: Event, day: u8, part: u8, input: &str) -> Result<Answer, RunError> {
+    solve_variant(event, day, DEFAULT_VARIANT, part, input)
+}
+
+/// Solve a part with a given variant of a registered day
+pub fn solve_variant(
+    event: Event,
+    day: u8,
+    variant: &str,
+    part: u8,
+    input: &str,
+) -> Result<Answer, RunError> {
+    let solution = find_solution(event, day, variant)?;
 
-    (run_fn[0].run)(args)
+    let result = match part {
+        1..=3 => (solution.solve)(input, part),
+        _ => Err(InputError::new(format!("no part {part}"))),
+    };
+    result.map_err(|err| SolveError::new(event, day, part, err, input).into())
 }
@@ -0,0 +1,62 @@
+use crate::{
+    answers::Answers,
+    args::{get_default_input, get_example_input},
+    html,
+    read::get_statement_file,
+    report,
+};
+use everybody_codes::{registered_days, variants, Event, DEFAULT_VARIANT};
+use std::{error::Error, fs, path::Path};
+
+pub fn list(root: &Path, event: Option<Event>) -> Result<(), Box<dyn Error>> {
+    let mut rows = Vec::new();
+    for (solution_event, day) in registered_days() {
+        if event.is_some_and(|event| event != solution_event) {
+            continue;
+        }
+
+        let answers = Answers::load(root, solution_event, day)?;
+        rows.push(vec![
+            solution_event.to_string(),
+            format!("{day:02}"),
+            parts_with(|part| get_default_input(root, solution_event, day, part).exists()),
+            parts_with(|part| get_example_input(root, solution_event, day, part).exists()),
+            parts_with(|part| answers.get(part).is_some()),
+            variants(solution_event, day)
+                .into_iter()
+                .filter(|&variant| variant != DEFAULT_VARIANT)
+                .collect::<Vec<_>>()
+                .join(", "),
+            fs::read_to_string(get_statement_file(root, solution_event, day, 1))
+                .ok()
+                .and_then(|statement| html::get_title(&statement))
+                .unwrap_or_default(),
+        ]);
+    }
+
+    if rows.is_empty() {
+        println!("No registered solution");
+        return Ok(());
+    }
+    report::print_table(
+        &[
+            "Event", "Day", "Inputs", "Examples", "Answers", "Variants", "Title",
+        ],
+        &rows,
+    );
+    Ok(())
+}
+
+/// Show the parts matching the predicate, e.g. `1 - 3`
+fn parts_with(has_part: impl Fn(u8) -> bool) -> String {
+    (1..=3)
+        .map(|part| {
+            if has_part(part) {
+                part.to_string()
+            } else {
+                String::from("-")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -1,15 +1,343 @@
-use everybody_codes::{run_solution, RunArgs};
-use std::process;
+mod answers;
+mod args;
+mod check;
+mod client;
+mod config;
+mod cross_check;
+mod decrypt;
+mod executor;
+mod extract;
+mod fetch;
+mod html;
+mod list;
+mod new;
+mod read;
+mod report;
+mod submit;
+mod watch;
+
+use answers::{Bound, Rejection, Rejections};
+use args::{Command, Format, RunArgs, Selection};
+use everybody_codes::{solve_variant, Answer, Event, RunError};
+use report::RunRecord;
+use std::{
+    error::Error,
+    num::NonZeroUsize,
+    path::Path,
+    process,
+    time::{Duration, Instant},
+};
+
+/// Exit code when a solution rejects its input, apart from other failures (1)
+/// and usage errors reported by clap (2)
+const INVALID_INPUT_EXIT_CODE: i32 = 3;
 
 fn main() {
-    let args = RunArgs::parse().unwrap_or_else(|err| {
+    let command = Command::parse().unwrap_or_else(|err| {
         println!("Error in arguments: {}", err);
         process::exit(1);
     });
 
-    let solution = run_solution(&args);
+    match command {
+        Command::Run {
+            selection,
+            watch: true,
+            ..
+        } => {
+            if let Err(err) = watch::watch(&selection) {
+                println!("Error watching: {err}");
+                process::exit(1);
+            }
+        }
+        Command::Run {
+            selection,
+            time,
+            format,
+            watch: false,
+            jobs,
+        } => run(&selection, time, format, jobs),
+        Command::CrossCheck { selection } => {
+            report::silence_panics();
+            if !cross_check::cross_check(selection.runs()) {
+                process::exit(1);
+            }
+        }
+        Command::Bench { selection, runs } => {
+            if let Err(err) = bench(selection.runs(), runs) {
+                println!("Error in input: {err}");
+                if let Some(RunError::Solve(_)) = err.downcast_ref() {
+                    process::exit(INVALID_INPUT_EXIT_CODE);
+                }
+                process::exit(1);
+            }
+        }
+        Command::Check {
+            selection,
+            record,
+            jobs,
+        } => {
+            report::silence_panics();
+            match check::check(selection.runs(), record, jobs) {
+                Ok(true) => (),
+                Ok(false) => process::exit(1),
+                Err(err) => {
+                    println!("Error in answers: {err}");
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Reject {
+            root,
+            event,
+            day,
+            part,
+            answer,
+            bound,
+        } => {
+            if let Err(err) = reject(&root, event, day, part, answer, bound) {
+                println!("Error in answers: {err}");
+                process::exit(1);
+            }
+        }
+        Command::List { root, event } => {
+            if let Err(err) = list::list(&root, event) {
+                println!("Error in answers: {err}");
+                process::exit(1);
+            }
+        }
+        Command::Fetch {
+            root,
+            event,
+            day,
+            cookie_file,
+            base_url,
+        } => {
+            let result = client::Client::new(&cookie_file, base_url.as_deref())
+                .and_then(|client| fetch::fetch(&client, &root, event, day));
+            if let Err(err) = result {
+                println!("Error fetching notes: {err}");
+                process::exit(1);
+            }
+        }
+        Command::Submit {
+            args,
+            cookie_file,
+            base_url,
+            force,
+        } => {
+            report::silence_panics();
+            let result = client::Client::new(&cookie_file, base_url.as_deref())
+                .and_then(|client| submit::submit(&client, &args, force));
+            match result {
+                Ok(true) => (),
+                Ok(false) => process::exit(1),
+                Err(err) => {
+                    println!("Error submitting answer: {err}");
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Read {
+            root,
+            event,
+            day,
+            part,
+            cookie_file,
+            base_url,
+            refresh,
+            color,
+        } => {
+            let connect = || client::Client::new(&cookie_file, base_url.as_deref());
+            if let Err(err) = read::read(&root, event, day, part, refresh, color, connect) {
+                println!("Error reading statement: {err}");
+                process::exit(1);
+            }
+        }
+        Command::Extract {
+            root,
+            event,
+            day,
+            force,
+        } => {
+            if let Err(err) = extract::extract(&root, event, day, force) {
+                println!("Error extracting examples: {err}");
+                process::exit(1);
+            }
+        }
+        Command::New {
+            src,
+            root,
+            event,
+            day,
+            answer_type,
+        } => {
+            if let Err(err) = new::create_day(&src, &root, event, day, answer_type) {
+                println!("Error creating day: {err}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn run(selection: &Selection, time: bool, format: Format, jobs: NonZeroUsize) {
+    if format != Format::Text {
+        report::silence_panics();
+    }
+
+    let (records, elapsed) = executor::run_all(selection.runs(), jobs);
+
+    match (format, selection) {
+        (Format::Json, _) => report::print_json(&records),
+        (Format::Csv, _) => report::print_csv(&records),
+        (Format::Text, Selection::All(_)) => print_summary(&records, time, elapsed),
+        (Format::Text, Selection::Day(_)) => {
+            records.iter().for_each(|record| print_record(record, time));
+            if time {
+                print_total_time(&records, elapsed);
+            }
+        }
+        (Format::Text, Selection::Single(_)) => print_record(&records[0], time),
+    }
+
+    if records.iter().any(|record| record.invalid_input) {
+        process::exit(INVALID_INPUT_EXIT_CODE);
+    }
+    if records
+        .iter()
+        .any(|record| record.error.is_some() || record.is_wrong())
+    {
+        process::exit(1);
+    }
+}
+
+fn print_record(record: &RunRecord, time: bool) {
+    match &record.answer {
+        Some(answer) => print!("{} Answer: {answer}", record.label()),
+        None => print!("{} {}", record.label(), record.outcome()),
+    }
+    match &record.expected {
+        Some(expected) if record.is_wrong() => print!(", expected {expected}"),
+        Some(_) if record.answer.is_some() => print!(", as expected"),
+        _ => (),
+    }
+    if time {
+        print!(" ({:.2?})", record.duration);
+    }
+    println!();
+    for warning in &record.warnings {
+        println!("  Warning: {warning}");
+    }
+}
+
+/// Wall-clock time of parallel runs, along with the time spent solving
+fn print_total_time(records: &[RunRecord], elapsed: Duration) {
+    let total: Duration = records.iter().map(|record| record.duration).sum();
     println!(
-        "{}/{:02}/{} Answer: {solution}",
-        args.year, args.day, args.part
+        "{} runs in {elapsed:.2?} ({total:.2?} of solving)",
+        records.len()
     );
 }
+
+fn print_summary(records: &[RunRecord], time: bool, elapsed: Duration) {
+    if records.is_empty() {
+        println!("No registered solution has an input file");
+        return;
+    }
+
+    let examples = records.iter().any(|record| record.expected.is_some());
+    let mut headers = vec!["Event", "Day", "Part", "Answer"];
+    if examples {
+        headers.push("Expected");
+    }
+    if time {
+        headers.push("Time");
+    }
+
+    let rows: Vec<Vec<String>> = records
+        .iter()
+        .map(|record| {
+            let mut row = vec![
+                record.event.to_string(),
+                format!("{:02}", record.day),
+                record.part.to_string(),
+                record.outcome(),
+            ];
+            if examples {
+                row.push(
+                    record
+                        .expected
+                        .as_ref()
+                        .map(Answer::to_string)
+                        .unwrap_or_default(),
+                );
+            }
+            if time {
+                row.push(format!("{:.2?}", record.duration));
+            }
+            row
+        })
+        .collect();
+
+    report::print_table(&headers, &rows);
+    for record in records {
+        for warning in &record.warnings {
+            println!("{} Warning: {warning}", record.label());
+        }
+    }
+    println!();
+    if time {
+        print_total_time(records, elapsed);
+    } else {
+        println!("{} runs", records.len());
+    }
+}
+
+fn reject(
+    root: &Path,
+    event: Event,
+    day: u8,
+    part: u8,
+    answer: Answer,
+    bound: Option<Bound>,
+) -> Result<(), Box<dyn Error>> {
+    let mut rejections = Rejections::load(root, event, day)?;
+    let rejection = Rejection { answer, bound };
+    println!("{event}/{day:02}/{part} Rejected: {rejection}");
+    rejections.add(part, rejection);
+    rejections.save(root, event, day)
+}
+
+fn bench(runs: &[RunArgs], nb_runs: u32) -> Result<(), Box<dyn Error>> {
+    if runs.is_empty() {
+        println!("No registered solution has an input file");
+        return Ok(());
+    }
+
+    let mut rows = Vec::new();
+    for args in runs {
+        let input = args.read_input()?;
+        let mut durations: Vec<Duration> = (0..nb_runs)
+            .map(|_| {
+                let start = Instant::now();
+                solve_variant(args.event, args.day, args.variant, args.part, &input)?;
+                Ok(start.elapsed())
+            })
+            .collect::<Result<_, RunError>>()?;
+        durations.sort();
+
+        let mut row = report::run_label(args);
+        row.extend(
+            [
+                durations[0],
+                durations[durations.len() / 2],
+                durations[durations.len() - 1],
+            ]
+            .map(|duration| format!("{duration:.2?}")),
+        );
+        rows.push(row);
+    }
+
+    println!("{nb_runs} runs per solution\n");
+    report::print_table(&["Event", "Day", "Part", "Min", "Median", "Max"], &rows);
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use crate::args::get_day_directory;
+use clap::ValueEnum;
+use everybody_codes::Event;
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Type returned by the `solve` function of a new day
+#[derive(Clone, Copy, ValueEnum)]
+pub enum AnswerType {
+    U32,
+    U64,
+    I64,
+    String,
+    Answer,
+}
+
+impl AnswerType {
+    fn header(self) -> &'static str {
+        match self {
+            Self::Answer => "use crate::{Answer, InputError};",
+            _ => "use crate::InputError;",
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::I64 => "i64",
+            Self::String => "String",
+            Self::Answer => "Answer",
+        }
+    }
+}
+
+pub fn get_source_file(src: &Path, event: Event, day: u8) -> PathBuf {
+    src.join(event.module_name())
+        .join(format!("{}.rs", event.quest_name(day)))
+}
+
+/// Scaffold the solution of a day and create its input directory, without
+/// overwriting anything. The build script registers the new day
+pub fn create_day(
+    src: &Path,
+    root: &Path,
+    event: Event,
+    day: u8,
+    answer_type: AnswerType,
+) -> Result<(), Box<dyn Error>> {
+    let day_file = get_source_file(src, event, day);
+    if day_file.exists() {
+        return Err(format!("{} already exists", day_file.display()).into());
+    }
+
+    fs::create_dir_all(src.join(event.module_name()))?;
+    fs::write(&day_file, day_template(answer_type))?;
+    println!("Created {}", day_file.display());
+
+    let input_dir = get_day_directory(root, event, day);
+    fs::create_dir_all(&input_dir)?;
+    println!("Created {}", input_dir.display());
+    Ok(())
+}
+
+fn day_template(answer_type: AnswerType) -> String {
+    format!(
+        "{}
+
+pub fn solve(data: &str, part: u8) -> Result<{}, InputError> {{
+    match part {{
+        1 => unimplemented!(),
+        2 => unimplemented!(),
+        3 => unimplemented!(),
+        _ => unreachable!(),
+    }}
+}}
+",
+        answer_type.header(),
+        answer_type.name(),
+    )
+}
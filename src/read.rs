@@ -0,0 +1,54 @@
+use crate::{answers::get_day_file, client::Client, decrypt, html};
+use everybody_codes::Event;
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub fn get_statement_file(root: &Path, event: Event, day: u8, part: u8) -> PathBuf {
+    get_day_file(root, event, day, &format!("part{part}.html"))
+}
+
+/// Print the statement of a part, downloading the statements of every unlocked
+/// part unless it is already cached or `refresh` is set. Without a part, show
+/// the latest cached one
+pub fn read(
+    root: &Path,
+    event: Event,
+    day: u8,
+    part: Option<u8>,
+    refresh: bool,
+    color: bool,
+    connect: impl FnOnce() -> Result<Client, Box<dyn Error>>,
+) -> Result<(), Box<dyn Error>> {
+    let cached = |part| get_statement_file(root, event, day, part).exists();
+    if refresh || !cached(part.unwrap_or(1)) {
+        download(&connect()?, root, event, day)?;
+    }
+
+    let part = part.unwrap_or_else(|| (1..=3).rev().find(|&part| cached(part)).unwrap_or(1));
+    let statement_file = get_statement_file(root, event, day, part);
+    let statement = fs::read_to_string(&statement_file)
+        .map_err(|_| format!("part {part} is not unlocked yet"))?;
+    print!("{}", html::render(&statement, color));
+    Ok(())
+}
+
+fn download(client: &Client, root: &Path, event: Event, day: u8) -> Result<(), Box<dyn Error>> {
+    let seed = client.get_seed()?;
+    let descriptions = client.get_encrypted_descriptions(event, day, seed)?;
+    let keys = client.get_keys(event, day)?;
+    keys.save(root, event, day)?;
+
+    for part in 1..=3 {
+        let (Some(key), Some(encrypted)) = (keys.get(part), descriptions.get(&part.to_string()))
+        else {
+            continue;
+        };
+        let statement = decrypt::decrypt_notes(encrypted, key)
+            .map_err(|err| format!("statement of part {part}: {err}"))?;
+        fs::write(get_statement_file(root, event, day, part), statement)?;
+    }
+    Ok(())
+}
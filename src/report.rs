@@ -0,0 +1,240 @@
+use crate::{
+    answers::{Answers, Rejections},
+    args::RunArgs,
+};
+use everybody_codes::{solve_variant, Answer, Event, RunError, DEFAULT_VARIANT};
+use serde::{Serialize, Serializer};
+use std::{
+    any::Any,
+    io, panic,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+#[derive(Serialize)]
+pub struct RunRecord {
+    /// Year of a yearly event, none for a story
+    pub year: Option<u32>,
+    pub event: Event,
+    pub day: u8,
+    pub part: u8,
+    pub variant: &'static str,
+    pub answer: Option<Answer>,
+    pub input: PathBuf,
+    #[serde(rename = "duration_ms", serialize_with = "serialize_millis")]
+    pub duration: Duration,
+    pub error: Option<String>,
+    pub warnings: Vec<String>,
+    /// Answer given by the statement for an example run
+    pub expected: Option<Answer>,
+    /// Whether the solution rejected its input, rather than panicking
+    #[serde(skip)]
+    pub invalid_input: bool,
+}
+
+impl RunRecord {
+    pub fn run(args: &RunArgs) -> Self {
+        Self::run_on(args, args.read_input().as_deref())
+    }
+
+    /// Run the solution on an input already read, e.g. shared by the variants
+    /// of a part when it comes from stdin
+    pub fn run_on(args: &RunArgs, input: Result<&str, &io::Error>) -> Self {
+        let mut invalid_input = false;
+        let (answer, error, duration) = match input {
+            Ok(input) => {
+                let start = Instant::now();
+                let result = panic::catch_unwind(|| {
+                    solve_variant(args.event, args.day, args.variant, args.part, input)
+                });
+                let duration = start.elapsed();
+
+                match result {
+                    Ok(Ok(answer)) => (Some(answer), None, duration),
+                    Ok(Err(RunError::Solve(err))) => {
+                        invalid_input = true;
+                        let error = format!("invalid input: {}", err.error);
+                        (None, Some(error), duration)
+                    }
+                    Ok(Err(err)) => (None, Some(err.to_string()), duration),
+                    Err(payload) => (None, Some(panic_message(payload)), duration),
+                }
+            }
+            Err(err) => (
+                None,
+                Some(format!("cannot read input: {err}")),
+                Duration::ZERO,
+            ),
+        };
+
+        let mut warnings = match &answer {
+            Some(answer) if args.uses_default_input() => {
+                Rejections::load(&args.root, args.event, args.day).map_or_else(
+                    |err| vec![err.to_string()],
+                    |rejections| rejections.warnings(args.part, answer),
+                )
+            }
+            _ => Vec::new(),
+        };
+
+        let expected = match Answers::load(&args.root, args.event, args.day) {
+            _ if !args.example => None,
+            Ok(answers) => answers.get_example(args.part).cloned(),
+            Err(err) => {
+                warnings.push(err.to_string());
+                None
+            }
+        };
+        if args.example && expected.is_none() {
+            warnings.push(String::from("no recorded answer for the example"));
+        }
+
+        let year = match args.event {
+            Event::Year(year) => Some(year),
+            Event::Story(_) => None,
+        };
+        Self {
+            year,
+            event: args.event,
+            day: args.day,
+            part: args.part,
+            variant: args.variant,
+            answer,
+            input: args.input_file.clone(),
+            duration,
+            error,
+            warnings,
+            expected,
+            invalid_input,
+        }
+    }
+
+    /// Whether the answer differs from the expected answer of the example
+    pub fn is_wrong(&self) -> bool {
+        matches!((&self.answer, &self.expected), (Some(answer), Some(expected)) if answer != expected)
+    }
+
+    pub fn label(&self) -> String {
+        let label = format!("{}/{:02}/{}", self.event, self.day, self.part);
+        if self.variant == DEFAULT_VARIANT {
+            label
+        } else {
+            format!("{label} ({})", self.variant)
+        }
+    }
+
+    pub fn outcome(&self) -> String {
+        match (&self.answer, &self.error) {
+            (Some(answer), _) => answer.to_string(),
+            (None, error) => format!("Error: {}", error.as_deref().unwrap_or_default()),
+        }
+    }
+}
+
+/// Errors are reported in the records themselves
+pub fn silence_panics() {
+    panic::set_hook(Box::new(|_| {}));
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("solution panicked")
+    }
+}
+
+fn serialize_millis<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+}
+
+pub fn print_json(records: &[RunRecord]) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(records).expect("Error serializing records")
+    );
+}
+
+pub fn print_csv(records: &[RunRecord]) {
+    println!("year,day,part,answer,input,duration_ms,error,warnings,expected,variant,event");
+    for record in records {
+        let fields = [
+            record.year.map(|year| year.to_string()).unwrap_or_default(),
+            record.day.to_string(),
+            record.part.to_string(),
+            record
+                .answer
+                .as_ref()
+                .map(Answer::to_string)
+                .unwrap_or_default(),
+            record.input.display().to_string(),
+            (record.duration.as_secs_f64() * 1000.0).to_string(),
+            record.error.clone().unwrap_or_default(),
+            record.warnings.join("; "),
+            record
+                .expected
+                .as_ref()
+                .map(Answer::to_string)
+                .unwrap_or_default(),
+            record.variant.to_string(),
+            record.event.to_string(),
+        ];
+        println!("{}", fields.map(|field| escape_csv(&field)).join(","));
+    }
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn print_table(headers: &[&str], rows: &[Vec<String>]) {
+    let widths: Vec<usize> = headers
+        .iter()
+        .enumerate()
+        .map(|(col, header)| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .chain([header.len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    let format_row = |cells: Vec<&str>| -> String {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!("{}", format_row(headers.to_vec()));
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|&width| "-".repeat(width))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+    for row in rows {
+        println!("{}", format_row(row.iter().map(String::as_str).collect()));
+    }
+}
+
+pub fn run_label(args: &RunArgs) -> Vec<String> {
+    vec![
+        args.event.to_string(),
+        format!("{:02}", args.day),
+        args.part.to_string(),
+    ]
+}
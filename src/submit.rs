@@ -0,0 +1,67 @@
+use crate::{
+    answers::{Answers, Rejection, Rejections},
+    args::RunArgs,
+    client::{Client, Verdict},
+    report::RunRecord,
+};
+use std::error::Error;
+
+/// Run the solution of a part on its input and submit the answer, recording
+/// the verdict of the site. Returns whether the answer was accepted
+pub fn submit(client: &Client, args: &RunArgs, force: bool) -> Result<bool, Box<dyn Error>> {
+    let record = RunRecord::run(args);
+    let label = record.label();
+    let Some(answer) = record.answer else {
+        return Err(format!("solution failed: {}", record.error.unwrap_or_default()).into());
+    };
+
+    let mut answers = Answers::load(&args.root, args.event, args.day)?;
+    match answers.get(args.part) {
+        Some(expected) if *expected == answer => {
+            println!("{label} Answer {answer} was already accepted");
+            return Ok(true);
+        }
+        Some(expected) if !force => {
+            return Err(format!("{expected} was already accepted, not submitting {answer}").into());
+        }
+        _ => (),
+    }
+    if !record.warnings.is_empty() && !force {
+        return Err(format!("not submitting {answer}: {}", record.warnings.join(", ")).into());
+    }
+
+    match client.submit_answer(args.event, args.day, args.part, &answer)? {
+        Verdict::Correct => {
+            println!("{label} Correct answer: {answer}");
+            answers.set(args.part, answer);
+            answers.save(&args.root, args.event, args.day)?;
+            Ok(true)
+        }
+        Verdict::Wrong {
+            length_correct,
+            first_correct,
+        } => {
+            let hint = |correct| if correct { "right" } else { "wrong" };
+            println!(
+                "{label} Wrong answer: {answer} (length is {}, first character is {})",
+                hint(length_correct),
+                hint(first_correct),
+            );
+            let mut rejections = Rejections::load(&args.root, args.event, args.day)?;
+            let known = rejections.get(args.part).iter().any(|r| r.answer == answer);
+            if !known {
+                let rejection = Rejection {
+                    answer,
+                    bound: None,
+                };
+                rejections.add(args.part, rejection);
+                rejections.save(&args.root, args.event, args.day)?;
+            }
+            Ok(false)
+        }
+        Verdict::TooEarly(message) => {
+            println!("{label} Too early to submit {answer}: {}", message.trim());
+            Ok(false)
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{
+    args::{RunArgs, Selection},
+    new,
+};
+use everybody_codes::Answer;
+use serde::Deserialize;
+use std::{
+    env,
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Fields of the records printed by a child run in JSON
+#[derive(Deserialize)]
+struct ChildRecord {
+    answer: Option<Answer>,
+    duration_ms: f64,
+    error: Option<String>,
+    warnings: Vec<String>,
+    expected: Option<Answer>,
+}
+
+struct Watched<'a> {
+    args: &'a RunArgs,
+    source: PathBuf,
+    answer: Option<Answer>,
+    duration: Option<Duration>,
+}
+
+/// Rebuild and run the selected parts again whenever the source of their day
+/// or their input changes, showing how their answers and timings evolve
+pub fn watch(selection: &Selection) -> Result<(), Box<dyn Error>> {
+    if selection
+        .runs()
+        .iter()
+        .any(|args| args.input_file == Path::new("-"))
+    {
+        return Err("cannot watch the standard input".into());
+    }
+    // The path of the executable is taken before it is replaced by a rebuild
+    let exe = env::current_exe()?;
+
+    let mut watched: Vec<Watched> = selection
+        .runs()
+        .iter()
+        .map(|args| Watched {
+            args,
+            source: new::get_source_file(
+                &Path::new(MANIFEST_DIR).join("src"),
+                args.event,
+                args.day,
+            ),
+            answer: None,
+            duration: None,
+        })
+        .collect();
+
+    let mut sources_time = None;
+    let mut inputs_time = None;
+    loop {
+        let new_sources_time = latest_modification(watched.iter().map(|w| &w.source));
+        let new_inputs_time = latest_modification(watched.iter().map(|w| &w.args.input_file));
+        if new_sources_time == sources_time && new_inputs_time == inputs_time {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+
+        let rebuild = new_sources_time != sources_time;
+        sources_time = new_sources_time;
+        inputs_time = new_inputs_time;
+        if rebuild && !build()? {
+            println!("Build failed, waiting for changes\n");
+            continue;
+        }
+        for watched in &mut watched {
+            run_child(&exe, watched)?;
+        }
+        println!();
+    }
+}
+
+fn latest_modification<'a>(paths: impl Iterator<Item = &'a PathBuf>) -> Option<SystemTime> {
+    paths
+        .filter_map(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+        .max()
+}
+
+/// Rebuild the crate with the profile of the running executable, returning
+/// whether the build succeeded
+fn build() -> Result<bool, Box<dyn Error>> {
+    let start = Instant::now();
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command
+        .args(["build", "--quiet", "--manifest-path"])
+        .arg(Path::new(MANIFEST_DIR).join("Cargo.toml"));
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    let status = command.status()?;
+    if status.success() {
+        println!("Built in {:.2?}", start.elapsed());
+    }
+    Ok(status.success())
+}
+
+fn run_child(exe: &Path, watched: &mut Watched) -> Result<(), Box<dyn Error>> {
+    let args = watched.args;
+    let mut command = Command::new(exe);
+    command
+        .arg("--root")
+        .arg(&args.root)
+        .args(["--format", "json", "--event", &args.event.to_string()])
+        .args([
+            "--day",
+            &args.day.to_string(),
+            "--part",
+            &args.part.to_string(),
+        ]);
+    command.args(["--variant", args.variant]);
+    if args.example {
+        command.arg("--example");
+    } else {
+        command.arg("--input").arg(&args.input_file);
+    }
+
+    let label = format!("{}/{:02}/{}", args.event, args.day, args.part);
+    let output = command.output()?;
+    let Ok([record]) = serde_json::from_slice::<[ChildRecord; 1]>(&output.stdout) else {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        println!(
+            "{label} Error: {}",
+            stdout.trim().to_string() + stderr.trim()
+        );
+        return Ok(());
+    };
+
+    let duration = Duration::from_secs_f64(record.duration_ms / 1000.0);
+    match (&record.answer, &watched.answer) {
+        (Some(answer), Some(previous)) if answer == previous => {
+            print!("{label} Answer: {answer} (unchanged)")
+        }
+        (Some(answer), Some(previous)) => print!("{label} Answer: {answer} (was {previous})"),
+        (Some(answer), None) => print!("{label} Answer: {answer}"),
+        (None, _) => print!(
+            "{label} Error: {}",
+            record.error.as_deref().unwrap_or_default()
+        ),
+    }
+    match &record.expected {
+        Some(expected) if record.answer.as_ref().is_some_and(|a| a != expected) => {
+            print!(", expected {expected}")
+        }
+        Some(_) if record.answer.is_some() => print!(", as expected"),
+        _ => (),
+    }
+    match watched.duration {
+        Some(previous) if record.answer.is_some() => {
+            println!(" ({duration:.2?}, {})", format_change(previous, duration))
+        }
+        _ => println!(" ({duration:.2?})"),
+    }
+    for warning in &record.warnings {
+        println!("  Warning: {warning}");
+    }
+
+    if record.answer.is_some() {
+        watched.answer = record.answer;
+        watched.duration = Some(duration);
+    }
+    Ok(())
+}
+
+/// Signed difference of a duration from its previous value
+fn format_change(previous: Duration, duration: Duration) -> String {
+    if duration >= previous {
+        format!("+{:.2?}", duration - previous)
+    } else {
+        format!("-{:.2?}", previous - duration)
+    }
+}
@@ -1,14 +1,19 @@
-use crate::args::RunArgs;
+use crate::InputError;
 
-use std::fs::read_to_string;
-
-pub fn run(args: &RunArgs) -> i32 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
+pub fn solve(data: &str, part: u8) -> Result<i32, InputError> {
+    // The last group may miss a single creature
+    let nb_creatures = data.chars().count();
+    if nb_creatures == 0 || (part == 3 && nb_creatures % 3 == 2) {
+        return Err(InputError::at_line(
+            0,
+            format!("{nb_creatures} creatures cannot be split in groups of {part}"),
+        ));
+    }
 
-    match args.part {
-        1 => run_part1(data),
-        2 => run_part2(data),
-        3 => run_part3(data),
+    match part {
+        1 => Ok(run_part1(data)),
+        2 => Ok(run_part2(data)),
+        3 => Ok(run_part3(data)),
         _ => unreachable!(),
     }
 }
@@ -22,11 +27,11 @@ fn get_score(c: char) -> i32 {
     }
 }
 
-fn run_part1(input: String) -> i32 {
+fn run_part1(input: &str) -> i32 {
     input.chars().map(get_score).sum()
 }
 
-fn run_part2(input: String) -> i32 {
+fn run_part2(input: &str) -> i32 {
     let mut score = 0;
     let input: Vec<char> = input.chars().collect();
     for i in (0..input.len() - 1).step_by(2) {
@@ -39,7 +44,7 @@ fn run_part2(input: String) -> i32 {
     score
 }
 
-fn run_part3(input: String) -> i32 {
+fn run_part3(input: &str) -> i32 {
     let mut score = 0;
     let input: Vec<char> = input.chars().collect();
     for i in (0..input.len() - 1).step_by(3) {
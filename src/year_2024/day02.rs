@@ -1,19 +1,23 @@
-use crate::args::RunArgs;
-
-use std::fs::read_to_string;
-
-pub fn run(args: &RunArgs) -> i32 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
+use crate::InputError;
 
+pub fn solve(data: &str, part: u8) -> Result<i32, InputError> {
     let mut lines = data.lines();
-    let needles: Vec<&str> = lines.next().unwrap()[6..].split(',').collect();
-    lines.next().unwrap();
+    let needles: Vec<&str> = lines
+        .next()
+        .and_then(|line| line.strip_prefix("WORDS:"))
+        .ok_or_else(|| InputError::at_line(0, "expected a `WORDS:` list"))?
+        .split(',')
+        .collect();
+    if needles.iter().any(|needle| needle.is_empty()) {
+        return Err(InputError::at_line(0, "empty word"));
+    }
+    lines.next();
     let haystacks: Vec<&str> = lines.collect();
 
-    match args.part {
-        1 => count_words(&haystacks, &needles),
-        2 => count_runes(&haystacks, &needles),
-        3 => find_words_in_grid(&haystacks, &needles),
+    match part {
+        1 => Ok(count_words(&haystacks, &needles)),
+        2 => Ok(count_runes(&haystacks, &needles)),
+        3 => Ok(find_words_in_grid(&haystacks, &needles)),
         _ => unreachable!(),
     }
 }
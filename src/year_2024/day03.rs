@@ -1,14 +1,14 @@
-use crate::args::RunArgs;
+use crate::InputError;
 
-use std::fs::read_to_string;
-
-pub fn run(args: &RunArgs) -> i32 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
+pub fn solve(data: &str, part: u8) -> Result<i32, InputError> {
     let grid: Vec<&str> = data.lines().collect();
+    if let Some(row) = grid.iter().position(|line| line.len() != grid[0].len()) {
+        return Err(InputError::at_line(row, "grid is not rectangular"));
+    }
 
-    match args.part {
-        1 | 2 => count_diggable_blocks(&grid, can_dig),
-        3 => count_diggable_blocks(&grid, can_dig_diagonaly),
+    match part {
+        1 | 2 => Ok(count_diggable_blocks(&grid, can_dig)),
+        3 => Ok(count_diggable_blocks(&grid, can_dig_diagonaly)),
         _ => unreachable!(),
     }
 }
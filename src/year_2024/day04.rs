@@ -1,14 +1,18 @@
-use crate::args::RunArgs;
+use crate::{parse_at, InputError};
 
-use std::fs::read_to_string;
+pub fn solve(data: &str, part: u8) -> Result<i32, InputError> {
+    let nails: Vec<i32> = data
+        .lines()
+        .enumerate()
+        .map(|(row, line)| parse_at(row, line))
+        .collect::<Result<_, _>>()?;
+    if nails.is_empty() {
+        return Err(InputError::new("no nail in input"));
+    }
 
-pub fn run(args: &RunArgs) -> i32 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
-    let nails: Vec<i32> = data.lines().map(|line| line.parse().unwrap()).collect();
-
-    match args.part {
-        1 | 2 => count_min_strikes(nails),
-        3 => count_min_strikes_and_pulls(nails),
+    match part {
+        1 | 2 => Ok(count_min_strikes(nails)),
+        3 => Ok(count_min_strikes_and_pulls(nails)),
         _ => unreachable!(),
   }
 }
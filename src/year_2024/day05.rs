@@ -1,21 +1,29 @@
-use crate::args::RunArgs;
+use crate::{parse_at, InputError};
 
 use std::collections::{HashMap, VecDeque};
-use std::fs::read_to_string;
 
-pub fn run(args: &RunArgs) -> i64 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
+pub fn solve(data: &str, part: u8) -> Result<i64, InputError> {
     let mut columns: [VecDeque<i64>; 4] = Default::default();
-    for line in data.lines() {
-        for (i, n) in line.split(' ').map(|c| c.parse().unwrap()).enumerate() {
+    for (row, line) in data.lines().enumerate() {
+        let numbers: Vec<i64> = line
+            .split(' ')
+            .map(|c| parse_at(row, c))
+            .collect::<Result<_, _>>()?;
+        if numbers.len() != 4 || numbers.iter().any(|&n| n <= 0) {
+            return Err(InputError::at_line(row, "expected 4 positive numbers"));
+        }
+        for (i, n) in numbers.into_iter().enumerate() {
             columns[i].push_back(n);
         }
     }
+    if columns[0].is_empty() {
+        return Err(InputError::new("no dancer in input"));
+    }
 
-    match args.part {
-        1 => dance_for(&mut columns, 10),
-        2 => dance_until_nth_repeat(&mut columns, 2024),
-        3 => get_largest_number(&mut columns),
+    match part {
+        1 => Ok(dance_for(&mut columns, 10)),
+        2 => Ok(dance_until_nth_repeat(&mut columns, 2024)),
+        3 => Ok(get_largest_number(&mut columns)),
         _ => unreachable!(),
     }
 }
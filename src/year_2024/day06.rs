@@ -1,43 +1,45 @@
-use crate::args::RunArgs;
+use crate::InputError;
 
-use std::{
-    collections::{HashMap, VecDeque},
-    fs::read_to_string,
-};
+use std::collections::{HashMap, VecDeque};
 
 type Tree<'a> = HashMap<&'a str, Vec<&'a str>>;
 
-pub fn run(args: &RunArgs) -> String {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
-
+pub fn solve(data: &str, part: u8) -> Result<String, InputError> {
     let mut tree = Tree::new();
     let mut parents: HashMap<&str, &str> = HashMap::new();
-    for line in data.lines() {
-        let (br_name, children) = line.split_once(':').unwrap();
+    for (row, line) in data.lines().enumerate() {
+        let (br_name, children) = line
+            .split_once(':')
+            .ok_or_else(|| InputError::at_line(row, "expected `branch:children`"))?;
         let children: Vec<&str> = children
             .split(',')
             .filter(|child| *child != "ANT" && *child != "BUG")
             .collect();
+        if br_name.is_empty() || children.iter().any(|child| child.is_empty()) {
+            return Err(InputError::at_line(row, "empty branch name"));
+        }
         for child in &children {
             parents.insert(child, br_name);
         }
         tree.insert(br_name, children);
     }
 
-    let unique_fruit_path = get_unique_fruit_path(&tree, &parents);
+    let unique_fruit_path = get_unique_fruit_path(&tree, &parents)?;
 
-    match args.part {
-        1 => unique_fruit_path.join(""),
-        2 | 3 => unique_fruit_path
+    match part {
+        1 => Ok(unique_fruit_path.join("")),
+        2 | 3 => Ok(unique_fruit_path
             .iter()
-            .map(|br| br.get(..1).unwrap())
-            .collect::<Vec<&str>>()
-            .join(""),
+            .map(|br| br.chars().next().unwrap())
+            .collect()),
         _ => unreachable!(),
     }
 }
 
-fn get_unique_fruit_path<'a>(tree: &Tree<'a>, parents: &HashMap<&'a str, &'a str>) -> Vec<&'a str> {
+fn get_unique_fruit_path<'a>(
+    tree: &Tree<'a>,
+    parents: &HashMap<&'a str, &'a str>,
+) -> Result<Vec<&'a str>, InputError> {
     let mut fruit_depths: HashMap<u16, Vec<&str>> = HashMap::new();
     let mut stack: VecDeque<(&str, u16)> = VecDeque::from([("RR", 0)]);
     while !stack.is_empty() {
@@ -59,14 +61,16 @@ fn get_unique_fruit_path<'a>(tree: &Tree<'a>, parents: &HashMap<&'a str, &'a str
         .filter(|(_, nodes)| nodes.len() == 1)
         .map(|(_, nodes)| nodes[0])
         .next()
-        .unwrap();
+        .ok_or_else(|| InputError::new("no fruit at a unique depth"))?;
     let mut path = Vec::new();
     while unique_fruit != "RR" {
         path.push(unique_fruit);
-        unique_fruit = parents.get(&unique_fruit).unwrap();
+        unique_fruit = parents
+            .get(&unique_fruit)
+            .ok_or_else(|| InputError::new(format!("branch {unique_fruit} has no parent")))?;
     }
     path.push("RR");
     path.reverse();
     path.push("@");
-    path
+    Ok(path)
 }
@@ -1,30 +1,33 @@
-use crate::args::RunArgs;
+use crate::{Answer, InputError};
 
-use std::{fmt::Display, fs::read_to_string};
+use std::cmp::Reverse;
 
 type Pos = (usize, usize);
 type Plan = (char, Vec<char>);
 
-pub fn run(args: &RunArgs) -> Box<dyn Display> {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
-
-    let (race_track, plans) = parse_input(data);
+pub fn solve(data: &str, part: u8) -> Result<Answer, InputError> {
+    let (race_track, plans) = parse_input(data)?;
     let race_track = race_track.chars().collect::<Vec<char>>();
 
-    match args.part {
-        1 => Box::new(String::from_iter(rank_plans(&race_track, &plans, 1))),
-        2 => Box::new(String::from_iter(rank_plans(&race_track, &plans, 10))),
-        3 => Box::new(count_winning_plans(&race_track, &plans[0], 2024)),
+    match part {
+        1 => Ok(String::from_iter(rank_plans(&race_track, &plans, 1)).into()),
+        2 => Ok(String::from_iter(rank_plans(&race_track, &plans, 10)).into()),
+        3 => {
+            let rival_plan = plans
+                .first()
+                .ok_or_else(|| InputError::new("no rival plan in input"))?;
+            Ok(count_winning_plans(&race_track, rival_plan, 2024).into())
+        }
         _ => unreachable!(),
     }
 }
 
-fn parse_input(data: String) -> (String, Vec<Plan>) {
+fn parse_input(data: &str) -> Result<(String, Vec<Plan>), InputError> {
     let mut racetrack_vec: Vec<Vec<char>> = Vec::new();
     let mut plans = Vec::new();
 
     let mut is_racetrack = true;
-    for line in data.lines() {
+    for (row, line) in data.lines().enumerate() {
         if line.is_empty() {
             is_racetrack = false;
             continue;
@@ -33,44 +36,58 @@ fn parse_input(data: String) -> (String, Vec<Plan>) {
         if is_racetrack {
             racetrack_vec.push(line.chars().collect());
         } else {
-            let (name, plan) = line.split_once(':').unwrap();
+            let invalid_plan = || InputError::at_line(row, "expected `name:action,...`");
+            let (name, plan) = line.split_once(':').ok_or_else(invalid_plan)?;
             plans.push((
-                name.chars().next().unwrap(),
-                plan.split(',').map(|c| c.chars().next().unwrap()).collect(),
+                name.chars().next().ok_or_else(invalid_plan)?,
+                plan.split(',')
+                    .map(|c| c.chars().next().ok_or_else(invalid_plan))
+                    .collect::<Result<_, _>>()?,
             ));
         }
     }
-    (parse_racetrack(racetrack_vec), plans)
+    Ok((parse_racetrack(racetrack_vec)?, plans))
 }
 
-fn parse_racetrack(racetrack_vec: Vec<Vec<char>>) -> String {
+fn parse_racetrack(racetrack_vec: Vec<Vec<char>>) -> Result<String, InputError> {
+    if racetrack_vec.first().is_none_or(|line| line.len() < 2) {
+        return Err(InputError::new(
+            "racetrack must start with `S` and a first step",
+        ));
+    }
+    let nb_cells: usize = racetrack_vec.iter().map(Vec::len).sum();
+
     let mut racetrack = String::new();
     let mut prev_pos = (0, 0);
     let mut pos = (0, 1);
     while pos != (0, 0) {
+        if racetrack.len() > nb_cells {
+            return Err(InputError::new("racetrack does not loop back to `S`"));
+        }
         racetrack.push(racetrack_vec[pos.0][pos.1]);
         let tmp_pos = pos;
-        pos = get_next_pos(&racetrack_vec, pos, prev_pos);
+        pos = get_next_pos(&racetrack_vec, pos, prev_pos)
+            .ok_or_else(|| InputError::at_line(pos.0, "racetrack is not a loop"))?;
         prev_pos = tmp_pos;
     }
     racetrack.push('S');
-    racetrack
+    Ok(racetrack)
 }
 
-fn get_next_pos(racetrack_vec: &[Vec<char>], pos: Pos, prev_pos: Pos) -> Pos {
+fn get_next_pos(racetrack_vec: &[Vec<char>], pos: Pos, prev_pos: Pos) -> Option<Pos> {
     for (dx, dy) in [(-1, 0), (0, 1), (1, 0), (0, -1)] {
         let next_x = pos.0.wrapping_add_signed(dx);
         let next_y = pos.1.wrapping_add_signed(dy);
 
         if (0..racetrack_vec.len()).contains(&next_x)
-            && (0..racetrack_vec[0].len()).contains(&next_y)
+            && (0..racetrack_vec[next_x].len()).contains(&next_y)
             && racetrack_vec[next_x][next_y] != ' '
             && (next_x != prev_pos.0 || next_y != prev_pos.1)
         {
-            return (next_x, next_y);
+            return Some((next_x, next_y));
         }
     }
-    unreachable!()
+    None
 }
 
 fn get_plan_score(track: &[char], plan: &[char], nb_loops: u16) -> u64 {
@@ -122,7 +139,7 @@ fn rank_plans(track: &[char], plans: &Vec<Plan>, nb_loops: u16) -> Vec<char> {
         let score = get_plan_score(track, plan, nb_loops);
         plan_scores.push((*name, score));
     }
-    plan_scores.sort_by(|a, b| b.1.cmp(&a.1));
+    plan_scores.sort_by_key(|&(_, score)| Reverse(score));
     plan_scores.iter().map(|(name, _)| *name).collect()
 }
 
@@ -1,15 +1,12 @@
-use crate::args::RunArgs;
+use crate::{parse_at, InputError};
 
-use std::fs::read_to_string;
+pub fn solve(data: &str, part: u8) -> Result<u64, InputError> {
+    let input = parse_at(0, data.trim())?;
 
-pub fn run(args: &RunArgs) -> u64 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
-    let input = data.trim().parse().unwrap();
-
-    match args.part {
-        1 => build_pyramid(input),
-        2 => build_tower(input),
-        3 => build_shrine(input),
+    match part {
+        1 => Ok(build_pyramid(input)),
+        2 => Ok(build_tower(input)),
+        3 => Ok(build_shrine(input)),
         _ => unreachable!(),
     }
 }
@@ -1,31 +1,38 @@
-use crate::args::RunArgs;
+use crate::{parse_at, InputError};
 
-use std::fs::read_to_string;
-
-pub fn run(args: &RunArgs) -> u32 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
-    let brightness_list: Vec<u32> = data.lines().map(|n| n.trim().parse().unwrap()).collect();
+pub fn solve(data: &str, part: u8) -> Result<u32, InputError> {
+    let brightness_list: Vec<u32> = data
+        .lines()
+        .enumerate()
+        .map(|(row, n)| parse_at(row, n.trim()))
+        .collect::<Result<_, _>>()?;
+    let max_brightness = *brightness_list
+        .iter()
+        .max()
+        .ok_or_else(|| InputError::new("no sparkball in input"))?;
 
     let mut stamps: Vec<u32> = vec![1, 3, 5, 10];
-    if args.part > 1 {
+    if part > 1 {
         stamps.extend_from_slice(&[15, 16, 20, 24, 25, 30]);
     }
-    if args.part == 3 {
+    if part == 3 {
         stamps.extend_from_slice(&[37, 38, 49, 50, 74, 75, 100, 101]);
     }
 
-    let count_fn = match args.part {
+    let count_fn = match part {
         1 => count_min_beetles_greedy,
         2 => count_min_beetles_dyn,
         3 => count_min_beetles_split,
         _ => unreachable!(),
     };
 
-    let mut cache = vec![0; *brightness_list.iter().max().unwrap() as usize];
-    brightness_list
+    // Part 3 splits a brightness in two halves up to 98 apart
+    let cache_size = max_brightness.max(max_brightness / 2 + 49);
+    let mut cache = vec![0; cache_size as usize];
+    Ok(brightness_list
         .iter()
         .map(|&b| count_fn(&mut cache, &stamps, b))
-        .sum()
+        .sum())
 }
 
 fn count_min_beetles_greedy(_: &mut [u32], stamps: &[u32], mut brightness: u32) -> u32 {
@@ -59,6 +66,7 @@ fn count_min_beetles_dyn(cache: &mut [u32], stamps: &[u32], brightness: u32) ->
 
 fn count_min_beetles_split(cache: &mut [u32], stamps: &[u32], brightness: u32) -> u32 {
     (0..50)
+        .take_while(|&diff| diff <= brightness / 2)
         .map(|diff| {
             count_min_beetles_dyn(cache, stamps, (brightness / 2) + diff)
                 + count_min_beetles_dyn(cache, stamps, (brightness / 2) - diff)
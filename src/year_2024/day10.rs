@@ -1,6 +1,4 @@
-use crate::args::RunArgs;
-
-use std::{fmt::Display, fs::read_to_string};
+use crate::{Answer, InputError};
 
 type Grid = Vec<Vec<char>>;
 type GridRef<'a> = Vec<&'a mut [char]>;
@@ -12,19 +10,27 @@ enum Status {
     Impossible,
 }
 
-pub fn run(args: &RunArgs) -> Box<dyn Display> {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
+pub fn solve(data: &str, part: u8) -> Result<Answer, InputError> {
     let mut data: Vec<Vec<char>> = data.lines().map(|line| line.chars().collect()).collect();
+    if data.len() < 8 {
+        return Err(InputError::new("wall is smaller than a single grid"));
+    }
+    if let Some(row) = data
+        .iter()
+        .position(|line| line.len() != data[0].len() || line.len() < 8)
+    {
+        return Err(InputError::at_line(row, "wall is not a rectangle of grids"));
+    }
 
-    match args.part {
-        1 => Box::new(read_and_fill(&mut data)),
-        2 => Box::new(read_and_fill_all(&mut data)),
-        3 => Box::new(read_and_fill_incomplete(&mut data)),
+    match part {
+        1 => Ok(read_and_fill(&mut data).into()),
+        2 => Ok(read_and_fill_all(&mut data).into()),
+        3 => Ok(read_and_fill_incomplete(&mut data).into()),
         _ => unreachable!(),
     }
 }
 
-fn get_split_grid_ref(grid: &mut Grid, row: usize, col: usize) -> GridRef {
+fn get_split_grid_ref(grid: &mut Grid, row: usize, col: usize) -> GridRef<'_> {
     let row_range = (9 * row)..(9 * (row + 1) - 1);
     let col_range = (9 * col)..(9 * (col + 1) - 1);
 
@@ -34,7 +40,7 @@ fn get_split_grid_ref(grid: &mut Grid, row: usize, col: usize) -> GridRef {
         .collect()
 }
 
-fn get_joined_grid_ref(grid: &mut Grid, row: usize, col: usize) -> GridRef {
+fn get_joined_grid_ref(grid: &mut Grid, row: usize, col: usize) -> GridRef<'_> {
     let row_range = (6 * row)..(6 * (row + 1) + 2);
     let col_range = (6 * col)..(6 * (col + 1) + 2);
 
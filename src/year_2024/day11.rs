@@ -1,31 +1,35 @@
-use crate::args::RunArgs;
+use crate::InputError;
 
-use std::{collections::HashMap, fs::read_to_string};
+use std::collections::HashMap;
 
 type Rules<'a> = HashMap<&'a str, Vec<&'a str>>;
 type Population<'a> = HashMap<&'a str, Vec<u64>>;
 
-pub fn run(args: &RunArgs) -> u64 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
-
+pub fn solve(data: &str, part: u8) -> Result<u64, InputError> {
     let rules: Rules = data
         .lines()
-        .map(|line| {
-            let (termite, next_gen) = line.split_once(':').unwrap();
-            (termite, next_gen.split(',').collect::<Vec<_>>())
+        .enumerate()
+        .map(|(row, line)| {
+            let (termite, next_gen) = line
+                .split_once(':')
+                .ok_or_else(|| InputError::at_line(row, "expected `termite:children`"))?;
+            Ok((termite, next_gen.split(',').collect::<Vec<_>>()))
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
     let mut population: Population = rules.keys().map(|termite| (*termite, Vec::new())).collect();
 
-    match args.part {
+    match part {
         1 => get_population_count(&rules, &mut population, "A", 4),
         2 => get_population_count(&rules, &mut population, "Z", 10),
         3 => {
             let pop_counts: Vec<u64> = rules
                 .keys()
                 .map(|termite| get_population_count(&rules, &mut population, termite, 20))
-                .collect();
-            pop_counts.iter().max().unwrap() - pop_counts.iter().min().unwrap()
+                .collect::<Result<_, _>>()?;
+            match (pop_counts.iter().max(), pop_counts.iter().min()) {
+                (Some(max), Some(min)) => Ok(max - min),
+                _ => Err(InputError::new("no termite in input")),
+            }
         }
         _ => unreachable!(),
     }
@@ -36,23 +40,24 @@ fn get_population_count<'a>(
     population: &mut Population<'a>,
     termite: &'a str,
     day: usize,
-) -> u64 {
+) -> Result<u64, InputError> {
     if day == 0 {
-        return 1;
+        return Ok(1);
     }
 
-    let pop_entry = population.get_mut(&termite).unwrap();
+    let unknown_termite = || InputError::new(format!("no rule for termite {termite}"));
+    let pop_entry = population.get_mut(&termite).ok_or_else(unknown_termite)?;
     if pop_entry.len() < day + 1 {
         pop_entry.extend(vec![0; day + 1 - pop_entry.len()]);
     }
     if pop_entry[day] > 0 {
-        return pop_entry[day];
+        return Ok(pop_entry[day]);
     }
 
     let mut pop_count = 0;
-    for child in rules.get(&termite).unwrap() {
-        pop_count += get_population_count(rules, population, child, day - 1);
+    for child in rules.get(&termite).ok_or_else(unknown_termite)? {
+        pop_count += get_population_count(rules, population, child, day - 1)?;
     }
     population.entry(termite).and_modify(|e| e[day] = pop_count);
-    pop_count
+    Ok(pop_count)
 }
@@ -1,24 +1,21 @@
-use crate::args::RunArgs;
-
-use std::fs::read_to_string;
+use crate::{parse_at, InputError};
 
 type Target = (usize, usize, bool);
 
-pub fn run(args: &RunArgs) -> u32 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
+pub fn solve(data: &str, part: u8) -> Result<u32, InputError> {
     let data: Vec<&str> = data.lines().collect::<Vec<_>>();
 
-    match args.part {
-        1 | 2 => parse_targets(&data)
+    match part {
+        1 | 2 => Ok(parse_targets(&data)
             .iter()
             .copied()
             .map(get_ranking_value)
-            .sum(),
-        3 => parse_meteors(&data)
+            .sum()),
+        3 => Ok(parse_meteors(&data)?
             .iter()
             .copied()
             .map(shoot_meteor)
-            .sum(),
+            .sum()),
         _ => unreachable!(),
     }
 }
@@ -59,11 +56,14 @@ fn get_ranking_value((mut x, mut y, double): Target) -> u32 {
     }
 }
 
-fn parse_meteors(data: &[&str]) -> Vec<(usize, usize)> {
+fn parse_meteors(data: &[&str]) -> Result<Vec<(usize, usize)>, InputError> {
     data.iter()
-        .map(|&line| {
-            let (x, y) = line.split_once(' ').unwrap();
-            (y.parse().unwrap(), x.parse().unwrap())
+        .enumerate()
+        .map(|(row, &line)| {
+            let (x, y) = line
+                .split_once(' ')
+                .ok_or_else(|| InputError::at_line(row, "expected `x y`"))?;
+            Ok((parse_at(row, y)?, parse_at(row, x)?))
         })
         .collect()
 }
@@ -84,7 +84,7 @@ fn ranking_score_to_meteor(row: usize, (x, y): (usize, usize)) -> Option<u32> {
         return None;
     }
 
-    if (x + y - row) % 3 != 0 {
+    if !(x + y - row).is_multiple_of(3) {
         return None;
     }
 
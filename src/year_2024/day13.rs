@@ -1,62 +1,93 @@
-use crate::args::RunArgs;
-
-use std::fs::read_to_string;
+use crate::InputError;
+use std::{cmp::Reverse, collections::BinaryHeap};
 
 type Pos = (usize, usize);
+type PathFinder = fn(&[Vec<char>], char, char) -> Result<u32, InputError>;
+
+pub fn solve(data: &str, part: u8) -> Result<u32, InputError> {
+    solve_with(data, part, get_shortest_path)
+}
+
+/// Same as `solve`, keeping the positions to visit in a binary heap
+pub fn solve_heap(data: &str, part: u8) -> Result<u32, InputError> {
+    solve_with(data, part, get_shortest_path_heap)
+}
 
-pub fn run(args: &RunArgs) -> u32 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
+fn solve_with(data: &str, part: u8, get_shortest_path: PathFinder) -> Result<u32, InputError> {
     let maze: Vec<Vec<char>> = data.lines().map(|line| line.chars().collect()).collect();
+    if maze.first().is_none_or(Vec::is_empty) {
+        return Err(InputError::new("empty maze"));
+    }
+    for (row, line) in maze.iter().enumerate() {
+        if line.len() != maze[0].len() {
+            return Err(InputError::at_line(row, "maze is not rectangular"));
+        }
+        if let Some(c) = line
+            .iter()
+            .find(|&&c| !is_wall(c) && get_height(c).is_none())
+        {
+            return Err(InputError::at_line(row, format!("unknown cell `{c}`")));
+        }
+    }
 
-    match args.part {
+    match part {
         1 | 2 => get_shortest_path(&maze, 'S', 'E'),
         3 => get_shortest_path(&maze, 'E', 'S'),
         _ => unreachable!(),
     }
 }
 
-fn get_pos(maze: &[Vec<char>], needle: char) -> Pos {
+fn get_pos(maze: &[Vec<char>], needle: char) -> Result<Pos, InputError> {
     maze.iter()
         .enumerate()
         .filter_map(|(row, line)| line.iter().position(|&c| c == needle).map(|col| (row, col)))
         .next()
-        .unwrap()
+        .ok_or_else(|| InputError::new(format!("no `{needle}` in maze")))
+}
+
+fn is_wall(c: char) -> bool {
+    c == '#' || c == ' '
 }
 
 fn get_neighbors(maze: &[Vec<char>], (x, y): Pos) -> Vec<Pos> {
     let mut neighbors = Vec::new();
-    if x > 0 && maze[x - 1][y] != '#' {
+    if x > 0 && !is_wall(maze[x - 1][y]) {
         neighbors.push((x - 1, y));
     }
-    if y < maze[0].len() - 1 && maze[x][y + 1] != '#' {
+    if y < maze[0].len() - 1 && !is_wall(maze[x][y + 1]) {
         neighbors.push((x, y + 1));
     }
-    if x < maze.len() - 1 && maze[x + 1][y] != '#' {
+    if x < maze.len() - 1 && !is_wall(maze[x + 1][y]) {
         neighbors.push((x + 1, y));
     }
-    if y > 0 && maze[x][y - 1] != '#' {
+    if y > 0 && !is_wall(maze[x][y - 1]) {
         neighbors.push((x, y - 1));
     }
     neighbors
 }
 
-fn get_height(c: char) -> i32 {
+fn get_height(c: char) -> Option<u32> {
     match c {
-        'S' | 'E' => 0,
-        c => c as i32 - '0' as i32,
+        'S' | 'E' => Some(0),
+        c => c.to_digit(10),
     }
 }
 
+// Cells are checked when parsing the maze
 fn get_dist(c1: char, c2: char) -> u32 {
-    let h1 = get_height(c1);
-    let h2 = get_height(c2);
+    let h1 = get_height(c1).unwrap();
+    let h2 = get_height(c2).unwrap();
 
     let dh = h1.abs_diff(h2);
     dh.min(10 - dh) + 1
 }
 
-fn get_shortest_path(maze: &[Vec<char>], start_char: char, end_char: char) -> u32 {
-    let start = get_pos(maze, start_char);
+fn get_shortest_path(
+    maze: &[Vec<char>],
+    start_char: char,
+    end_char: char,
+) -> Result<u32, InputError> {
+    let start = get_pos(maze, start_char)?;
 
     let mut seen: Vec<Vec<bool>> = vec![vec![false; maze[0].len()]; maze.len()];
     let mut dist: Vec<Vec<u32>> = vec![vec![u32::MAX; maze[0].len()]; maze.len()];
@@ -71,7 +102,7 @@ fn get_shortest_path(maze: &[Vec<char>], start_char: char, end_char: char) -> u3
         );
 
         if maze[x][y] == end_char {
-            return dist[x][y];
+            return Ok(dist[x][y]);
         }
 
         if seen[x][y] {
@@ -92,5 +123,41 @@ fn get_shortest_path(maze: &[Vec<char>], start_char: char, end_char: char) -> u3
         }
     }
 
-    unreachable!();
+    Err(InputError::new(format!(
+        "no path from `{start_char}` to `{end_char}`"
+    )))
+}
+
+fn get_shortest_path_heap(
+    maze: &[Vec<char>],
+    start_char: char,
+    end_char: char,
+) -> Result<u32, InputError> {
+    let start = get_pos(maze, start_char)?;
+
+    let mut dist: Vec<Vec<u32>> = vec![vec![u32::MAX; maze[0].len()]; maze.len()];
+    dist[start.0][start.1] = 0;
+
+    let mut heap = BinaryHeap::from([Reverse((0, start))]);
+    while let Some(Reverse((curr_dist, (x, y)))) = heap.pop() {
+        if maze[x][y] == end_char {
+            return Ok(curr_dist);
+        }
+
+        if curr_dist > dist[x][y] {
+            continue;
+        }
+
+        for (nx, ny) in get_neighbors(maze, (x, y)) {
+            let ndist = curr_dist + get_dist(maze[x][y], maze[nx][ny]);
+            if ndist < dist[nx][ny] {
+                dist[nx][ny] = ndist;
+                heap.push(Reverse((ndist, (nx, ny))));
+            }
+        }
+    }
+
+    Err(InputError::new(format!(
+        "no path from `{start_char}` to `{end_char}`"
+    )))
 }
@@ -1,9 +1,6 @@
-use crate::args::RunArgs;
+use crate::InputError;
 
-use std::{
-    collections::{HashSet, VecDeque},
-    fs::read_to_string,
-};
+use std::collections::{HashSet, VecDeque};
 
 type Pos = (i32, i32, i32);
 
@@ -13,43 +10,57 @@ struct Segment {
 }
 
 impl Segment {
-    fn new(input: &str) -> Self {
-        Self {
-            direction: input.chars().next().unwrap(),
-            length: input[1..].trim().parse().unwrap(),
+    fn new(row: usize, input: &str) -> Result<Self, InputError> {
+        let invalid_segment = || InputError::at_line(row, format!("invalid segment `{input}`"));
+        let direction = input.chars().next().ok_or_else(invalid_segment)?;
+        if !"UDRLFB".contains(direction) {
+            return Err(invalid_segment());
         }
+
+        Ok(Self {
+            direction,
+            length: input[1..].trim().parse().map_err(|_| invalid_segment())?,
+        })
     }
 }
 
-pub fn run(args: &RunArgs) -> u32 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
+pub fn solve(data: &str, part: u8) -> Result<u32, InputError> {
     let branches: Vec<Vec<Segment>> = data
         .lines()
-        .map(|line| line.split(',').map(Segment::new).collect())
-        .collect();
+        .enumerate()
+        .map(|(row, line)| {
+            line.split(',')
+                .map(|segment| Segment::new(row, segment))
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+    if branches.is_empty() {
+        return Err(InputError::new("no branch in input"));
+    }
 
-    match args.part {
+    match part {
         1 => get_height(&branches[0]),
-        2 => build_tree(&branches).0.len() as u32,
+        2 => Ok(build_tree(&branches).0.len() as u32),
         3 => get_murkiness_level(&branches),
         _ => unreachable!(),
     }
 }
 
-fn get_height(segments: &[Segment]) -> u32 {
-    let mut max_height = 0;
-    let mut height = 0;
+fn get_height(segments: &[Segment]) -> Result<u32, InputError> {
+    let mut max_height: i64 = 0;
+    let mut height: i64 = 0;
     for segment in segments {
         if segment.direction == 'U' {
-            height += segment.length;
+            height += i64::from(segment.length);
             max_height = max_height.max(height);
         } else if segment.direction == 'D' {
-            height -= segment.length;
+            height -= i64::from(segment.length);
         }
     }
-    max_height
+    u32::try_from(max_height).map_err(|_| InputError::new("tree is too high"))
 }
 
+// Directions are checked when parsing segments
 fn step((x, y, z): (i32, i32, i32), dir: char) -> (i32, i32, i32) {
     match dir {
         'U' => (x + 1, y, z),
@@ -79,11 +90,14 @@ fn build_tree(branches: &[Vec<Segment>]) -> (HashSet<Pos>, Vec<Pos>) {
     (seg_pos, leave_pos)
 }
 
-fn get_leaf_distances(segments: &HashSet<Pos>, leaves: &[Pos], height: u32) -> u32 {
+fn get_leaf_distances(
+    segments: &HashSet<Pos>,
+    leaves: &[Pos],
+    start: &Pos,
+) -> Result<u32, InputError> {
     let mut total_dist = 0;
     let mut nb_leaves_found = 0;
 
-    let start = segments.get(&(height as i32, 0, 0)).unwrap();
     let mut added: HashSet<&Pos> = HashSet::from([start]);
     let mut queue: VecDeque<(&Pos, u32)> = VecDeque::from([(start, 0)]);
     while let Some((pos, dist)) = queue.pop_front() {
@@ -91,7 +105,7 @@ fn get_leaf_distances(segments: &HashSet<Pos>, leaves: &[Pos], height: u32) -> u
             total_dist += dist;
             nb_leaves_found += 1;
             if nb_leaves_found == leaves.len() {
-                return total_dist;
+                return Ok(total_dist);
             }
         }
 
@@ -114,20 +128,22 @@ fn get_leaf_distances(segments: &HashSet<Pos>, leaves: &[Pos], height: u32) -> u
             }
         }
     }
-    unreachable!();
+    Err(InputError::new("leaves are not all connected to the trunk"))
 }
 
-fn get_murkiness_level(branches: &[Vec<Segment>]) -> u32 {
+fn get_murkiness_level(branches: &[Vec<Segment>]) -> Result<u32, InputError> {
     let (segments, leaves) = build_tree(branches);
-    let max_height = segments
+    let trunk: Vec<&Pos> = segments
         .iter()
-        .filter(|pos| pos.1 == 0 && pos.2 == 0)
-        .map(|pos| pos.0 as u32)
-        .max()
-        .unwrap();
-    (0..max_height)
-        .filter(|&height| segments.contains(&(height as i32, 0, 0)))
-        .map(|height| get_leaf_distances(&segments, &leaves, height))
+        .filter(|pos| pos.0 >= 0 && pos.1 == 0 && pos.2 == 0)
+        .collect();
+    let max_height = trunk.iter().map(|pos| pos.0).max().unwrap_or(0);
+    trunk
+        .into_iter()
+        .filter(|pos| pos.0 < max_height)
+        .map(|start| get_leaf_distances(&segments, &leaves, start))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
         .min()
-        .unwrap()
+        .ok_or_else(|| InputError::new("tree has no trunk"))
 }
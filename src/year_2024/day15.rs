@@ -1,16 +1,20 @@
-use crate::args::RunArgs;
+use crate::InputError;
 
-use std::{collections::VecDeque, fs::read_to_string};
+use std::collections::VecDeque;
 
-pub fn run(args: &RunArgs) -> u32 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
+pub fn solve(data: &str, part: u8) -> Result<u32, InputError> {
     let mut map: Vec<Vec<char>> = data.lines().map(|line| line.chars().collect()).collect();
-    if args.part == 3 {
-        map[75][168] = 'L';
+    if let Some(row) = map.iter().position(|line| line.len() != map[0].len()) {
+        return Err(InputError::at_line(row, "map is not rectangular"));
+    }
+    if part == 3 {
+        *map.get_mut(75)
+            .and_then(|line| line.get_mut(168))
+            .ok_or_else(|| InputError::new("map is too small to hold the herb L"))? = 'L';
     }
     let map: Vec<&[char]> = map.iter().map(|line| line.as_slice()).collect();
 
-    match args.part {
+    match part {
         1 => find_single_herb(&map, 'H'),
         2 => solve_maze(&map),
         3 => solve_large_maze(&map),
@@ -54,8 +58,15 @@ fn get_neighbors(map: &[&[char]], (x, y): (usize, usize)) -> Vec<(usize, usize)>
     neighbors
 }
 
-fn find_single_herb(map: &[&[char]], herb: char) -> u32 {
-    let start = (0, map[0].iter().position(|&c| c == '.').unwrap());
+fn find_entrance(map: &[&[char]]) -> Result<(usize, usize), InputError> {
+    map.first()
+        .and_then(|line| line.iter().position(|&c| c == '.'))
+        .map(|col| (0, col))
+        .ok_or_else(|| InputError::at_line(0, "no entrance on the first row"))
+}
+
+fn find_single_herb(map: &[&[char]], herb: char) -> Result<u32, InputError> {
+    let start = find_entrance(map)?;
 
     let mut seen = vec![vec![false; map[0].len()]; map.len()];
     seen[start.0][start.1] = true;
@@ -63,7 +74,7 @@ fn find_single_herb(map: &[&[char]], herb: char) -> u32 {
     let mut queue: VecDeque<((usize, usize), u32)> = VecDeque::from([(start, 0)]);
     while let Some((pos, dist)) = queue.pop_front() {
         if map[pos.0][pos.1] == herb {
-            return 2 * dist;
+            return Ok(2 * dist);
         }
 
         for (nx, ny) in get_neighbors(map, pos) {
@@ -74,18 +85,18 @@ fn find_single_herb(map: &[&[char]], herb: char) -> u32 {
             queue.push_back(((nx, ny), dist + 1));
         }
     }
-    unreachable!()
+    Err(InputError::new(format!("no reachable herb {herb}")))
 }
 
-fn solve_maze(map: &[&[char]]) -> u32 {
-    find_all_herbs(
-        map,
-        (0, map[0].iter().position(|&c| c == '.').unwrap()),
-        0b11111,
-    )
+fn solve_maze(map: &[&[char]]) -> Result<u32, InputError> {
+    find_all_herbs(map, find_entrance(map)?, 0b11111)
 }
 
-fn find_all_herbs(map: &[&[char]], start_pos: (usize, usize), target_herbs: usize) -> u32 {
+fn find_all_herbs(
+    map: &[&[char]],
+    start_pos: (usize, usize),
+    target_herbs: usize,
+) -> Result<u32, InputError> {
     let start = Node {
         pos: start_pos,
         herbs: 0,
@@ -98,7 +109,7 @@ fn find_all_herbs(map: &[&[char]], start_pos: (usize, usize), target_herbs: usiz
     let mut queue: VecDeque<Node> = VecDeque::from([start.clone()]);
     while let Some(node) = queue.pop_front() {
         if node.pos == start.pos && node.herbs == target_herbs {
-            return node.dist;
+            return Ok(node.dist);
         }
 
         for (nx, ny) in get_neighbors(map, node.pos) {
@@ -116,22 +127,18 @@ fn find_all_herbs(map: &[&[char]], start_pos: (usize, usize), target_herbs: usiz
             queue.push_back(neighbor);
         }
     }
-    unreachable!()
+    Err(InputError::new("some herbs cannot be reached"))
 }
 
-fn solve_large_maze(map: &[&[char]]) -> u32 {
+fn solve_large_maze(map: &[&[char]]) -> Result<u32, InputError> {
     let n = map[0].len() / 3;
 
     let first_map: Vec<&[char]> = map.iter().map(|&line| &line[..n]).collect();
     let second_map: Vec<&[char]> = map.iter().map(|&line| &line[n..2 * n]).collect();
     let third_map: Vec<&[char]> = map.iter().map(|&line| &line[2 * n..]).collect();
     let mut total_dist = 0;
-    total_dist += find_all_herbs(
-        &second_map,
-        (0, second_map[0].iter().position(|&c| c == '.').unwrap()),
-        0b111111,
-    );
-    total_dist += find_all_herbs(&first_map, (map.len() - 2, n - 1), 0b11111) + 4;
-    total_dist += find_all_herbs(&third_map, (map.len() - 2, 0), 0b11111) + 4;
-    total_dist
+    total_dist += find_all_herbs(&second_map, find_entrance(&second_map)?, 0b111111)?;
+    total_dist += find_all_herbs(&first_map, (map.len() - 2, n - 1), 0b11111)? + 4;
+    total_dist += find_all_herbs(&third_map, (map.len() - 2, 0), 0b11111)? + 4;
+    Ok(total_dist)
 }
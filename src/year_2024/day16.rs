@@ -1,6 +1,6 @@
-use crate::args::RunArgs;
+use crate::{parse_at, Answer, InputError};
 
-use std::{collections::HashMap, fmt::Display, fs::read_to_string};
+use std::collections::HashMap;
 
 struct Machine {
     steps: Vec<u32>,
@@ -8,16 +8,19 @@ struct Machine {
 }
 
 impl Machine {
-    fn new(input: &str) -> Self {
-        let mut input_it = input.lines();
-        let steps: Vec<u32> = input_it
+    fn new(input: &str) -> Result<Self, InputError> {
+        let steps: Vec<u32> = input
+            .lines()
             .next()
-            .unwrap()
+            .ok_or_else(|| InputError::new("no wheel steps in input"))?
             .split(',')
-            .map(|s| s.parse().unwrap())
-            .collect();
+            .map(|s| parse_at(0, s))
+            .collect::<Result<_, _>>()?;
         let mut cats = vec![Vec::new(); steps.len()];
-        for line in input_it.skip(1) {
+        for (row, line) in input.lines().enumerate().skip(2) {
+            if !line.is_ascii() || (line.len() + 1) / 4 > steps.len() {
+                return Err(InputError::at_line(row, "cats do not fit on the wheels"));
+            }
             let nb_cols = (line.len() + 1) / 4;
             for strip in 0..nb_cols {
                 let cat = &line[4 * strip..4 * (strip + 1) - 1];
@@ -26,8 +29,11 @@ impl Machine {
                 }
             }
         }
+        if let Some(wheel) = cats.iter().position(Vec::is_empty) {
+            return Err(InputError::new(format!("wheel {} has no cat", wheel + 1)));
+        }
 
-        Self { steps, cats }
+        Ok(Self { steps, cats })
     }
 
     fn get_frequency(&self) -> u32 {
@@ -65,14 +71,13 @@ impl Machine {
 
 type Cache = HashMap<(Vec<u32>, u32), (u64, u64)>;
 
-pub fn run(args: &RunArgs) -> Box<dyn Display> {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
-    let machine = Machine::new(&data);
+pub fn solve(data: &str, part: u8) -> Result<Answer, InputError> {
+    let machine = Machine::new(data)?;
 
-    match args.part {
-        1 => Box::new(simulate_rolls(&machine, 100)),
-        2 => Box::new(compute_coins(&machine, 202420242024)),
-        3 => Box::new(compute_coin_range(&machine, 256)),
+    match part {
+        1 => Ok(simulate_rolls(&machine, 100).into()),
+        2 => Ok(compute_coins(&machine, 202420242024).into()),
+        3 => Ok(compute_coin_range(&machine, 256).into()),
         _ => unreachable!(),
     }
 }
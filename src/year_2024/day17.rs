@@ -1,9 +1,6 @@
-use crate::args::RunArgs;
+use crate::InputError;
 
-use std::fs::read_to_string;
-
-pub fn run(args: &RunArgs) -> u32 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
+pub fn solve(data: &str, part: u8) -> Result<u32, InputError> {
     let star_pos: Vec<(usize, usize)> = data
         .lines()
         .enumerate()
@@ -16,9 +13,13 @@ pub fn run(args: &RunArgs) -> u32 {
         })
         .collect();
 
-    match args.part {
-        1 | 2 => get_constellation_size(&star_pos),
-        3 => get_small_constellation_size(&star_pos),
+    if star_pos.is_empty() {
+        return Err(InputError::new("no star in input"));
+    }
+
+    match part {
+        1 | 2 => Ok(get_constellation_size(&star_pos)),
+        3 => Ok(get_small_constellation_size(&star_pos)),
         _ => unreachable!(),
     }
 }
@@ -1,12 +1,17 @@
-use crate::args::RunArgs;
+use crate::InputError;
 
-use std::{collections::VecDeque, fs::read_to_string};
+use std::collections::VecDeque;
 
-pub fn run(args: &RunArgs) -> u32 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
+pub fn solve(data: &str, part: u8) -> Result<u32, InputError> {
     let map: Vec<Vec<char>> = data.lines().map(|line| line.chars().collect()).collect();
+    if map.first().is_none_or(Vec::is_empty) {
+        return Err(InputError::new("empty map"));
+    }
+    if let Some(row) = map.iter().position(|line| line.len() != map[0].len()) {
+        return Err(InputError::at_line(row, "map is not rectangular"));
+    }
 
-    match args.part {
+    match part {
         1 | 2 => compute_water_time(&map),
         3 => compute_water_time_from_well(&map),
         _ => unreachable!(),
@@ -48,7 +53,7 @@ fn get_neighbors(map: &[Vec<char>], (x, y): (usize, usize)) -> Vec<(usize, usize
     neighbors
 }
 
-fn compute_water_time(map: &[Vec<char>]) -> u32 {
+fn compute_water_time(map: &[Vec<char>]) -> Result<u32, InputError> {
     let (start_positions, mut nb_palm_trees) = parse_map(map);
 
     let mut added = vec![vec![false; map[0].len()]; map.len()];
@@ -62,7 +67,7 @@ fn compute_water_time(map: &[Vec<char>]) -> u32 {
         if map[x][y] == 'P' {
             nb_palm_trees -= 1;
             if nb_palm_trees == 0 {
-                return dist;
+                return Ok(dist);
             }
         }
 
@@ -74,10 +79,12 @@ fn compute_water_time(map: &[Vec<char>]) -> u32 {
             queue.push_back(((nx, ny), dist + 1));
         }
     }
-    unreachable!();
+    Err(InputError::new(format!(
+        "{nb_palm_trees} palm trees cannot be reached by water"
+    )))
 }
 
-fn compute_water_time_from_well(map: &[Vec<char>]) -> u32 {
+fn compute_water_time_from_well(map: &[Vec<char>]) -> Result<u32, InputError> {
     let palm_trees: Vec<(usize, usize)> = (0..map.len())
         .flat_map(|row| {
             map[row]
@@ -118,5 +125,5 @@ fn compute_water_time_from_well(map: &[Vec<char>]) -> u32 {
                 .collect::<Vec<_>>()
         })
         .min()
-        .unwrap()
+        .ok_or_else(|| InputError::new("no place reaches all palm trees"))
 }
@@ -1,13 +1,9 @@
-use crate::args::RunArgs;
+use crate::InputError;
 
-use std::fs::read_to_string;
+pub fn solve(data: &str, part: u8) -> Result<String, InputError> {
+    let (key, grid) = parse_input(data)?;
 
-pub fn run(args: &RunArgs) -> String {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
-
-    let (key, grid) = parse_input(&data);
-
-    let nb_rounds = match args.part {
+    let nb_rounds = match part {
         1 => 1,
         2 => 100,
         3 => 1048576000,
@@ -17,12 +13,27 @@ pub fn run(args: &RunArgs) -> String {
     decrypt_message(&grid, &key, nb_rounds)
 }
 
-fn parse_input(data: &str) -> (Vec<char>, Vec<Vec<char>>) {
+fn parse_input(data: &str) -> Result<(Vec<char>, Vec<Vec<char>>), InputError> {
     let mut data_it = data.lines();
-    let key = data_it.next().unwrap().chars().collect();
+    let key: Vec<char> = data_it.next().unwrap_or_default().chars().collect();
+    if key.is_empty() || key.iter().any(|&c| c != 'L' && c != 'R') {
+        return Err(InputError::at_line(
+            0,
+            "key should only contain `L` and `R`",
+        ));
+    }
     data_it.next();
-    let grid = data_it.map(|line| line.chars().collect()).collect();
-    (key, grid)
+    let grid: Vec<Vec<char>> = data_it.map(|line| line.chars().collect()).collect();
+    if grid.len() < 3 {
+        return Err(InputError::new("message is smaller than a rotation"));
+    }
+    if let Some(row) = grid
+        .iter()
+        .position(|line| line.len() != grid[0].len() || line.len() < 3)
+    {
+        return Err(InputError::at_line(row + 2, "message is not a rectangle"));
+    }
+    Ok((key, grid))
 }
 
 fn rotate_grid<T: Copy>(grid: &mut [Vec<T>], row: usize, col: usize, rotation: char) {
@@ -95,7 +106,7 @@ fn compute_cycles(mapping: &[Vec<(usize, usize)>]) -> Vec<Vec<(usize, usize)>> {
     cycles
 }
 
-fn decrypt_grid(grid: &[Vec<char>]) -> String {
+fn decrypt_grid(grid: &[Vec<char>]) -> Result<String, InputError> {
     for line in grid {
         let mut beg_ind: usize = 0;
         for ind in 0..line.len() {
@@ -103,11 +114,13 @@ fn decrypt_grid(grid: &[Vec<char>]) -> String {
                 beg_ind = ind + 1;
             }
             if line[ind] == '<' {
-                return line[beg_ind..ind].iter().collect();
+                return Ok(line[beg_ind..ind].iter().collect());
             }
         }
     }
-    unreachable!()
+    Err(InputError::new(
+        "no message between `>` and `<` once decrypted",
+    ))
 }
 
 fn decrypt_cycle(
@@ -124,7 +137,7 @@ fn decrypt_cycle(
     }
 }
 
-fn decrypt_message(grid: &[Vec<char>], key: &[char], nb_rounds: u32) -> String {
+fn decrypt_message(grid: &[Vec<char>], key: &[char], nb_rounds: u32) -> Result<String, InputError> {
     // round_mapping[x][y] == initial position of character at (x, y) after a cycle of rotation
     let round_mapping = compute_round_mapping(grid, key);
 
@@ -1,6 +1,6 @@
-use crate::args::RunArgs;
+use crate::InputError;
 
-use std::{collections::VecDeque, fs::read_to_string};
+use std::collections::VecDeque;
 
 // (x, y, direction)
 type Pos = (usize, usize, usize);
@@ -8,11 +8,16 @@ type Pos = (usize, usize, usize);
 // (x, y, direction, checkpoint_seen, time)
 type State = (usize, usize, usize, usize, u32);
 
-pub fn run(args: &RunArgs) -> u32 {
-    let data = read_to_string(&args.input_file).expect("Error opening input file");
+pub fn solve(data: &str, part: u8) -> Result<u32, InputError> {
     let map: Vec<Vec<char>> = data.lines().map(|line| line.chars().collect()).collect();
+    if map.first().is_none_or(Vec::is_empty) {
+        return Err(InputError::new("empty map"));
+    }
+    if let Some(row) = map.iter().position(|line| line.len() != map[0].len()) {
+        return Err(InputError::at_line(row, "map is not rectangular"));
+    }
 
-    match args.part {
+    match part {
         1 => fly_for(&map, 100),
         2 => find_path(&map),
         3 => fly_for_max(&map, 384400),
@@ -37,25 +42,23 @@ fn get_neighbors(map: &[Vec<char>], (x, y, dir): Pos) -> Vec<Pos> {
     neighbors
 }
 
-fn find_start(map: &[Vec<char>]) -> (usize, usize) {
+fn find_start(map: &[Vec<char>]) -> Result<(usize, usize), InputError> {
     for (row, line) in map.iter().enumerate() {
         for (col, &c) in line.iter().enumerate() {
             if c == 'S' {
-                return (row, col);
+                return Ok((row, col));
             }
         }
     }
-    unreachable!();
+    Err(InputError::new("no start `S` in map"))
 }
 
-fn fly_for(map: &[Vec<char>], nb_seconds: u32) -> u32 {
+fn fly_for(map: &[Vec<char>], nb_seconds: u32) -> Result<u32, InputError> {
     let mut min_altitudes = vec![vec![vec![0; 4]; map[0].len()]; map.len()];
 
-    let start_pos = find_start(map);
+    let start_pos = find_start(map)?;
 
-    for dir in 0..4 {
-        min_altitudes[start_pos.0][start_pos.1][dir] = 1000;
-    }
+    min_altitudes[start_pos.0][start_pos.1].fill(1000);
     let mut states: Vec<Pos> = Vec::from_iter((0..4).map(|dir| (start_pos.0, start_pos.1, dir)));
     for _ in 0..nb_seconds {
         let mut next_states: Vec<Pos> = Vec::new();
@@ -66,7 +69,12 @@ fn fly_for(map: &[Vec<char>], nb_seconds: u32) -> u32 {
                     '+' => min_alt + 1,
                     '-' => min_alt - 2,
                     '.' | 'S' => min_alt - 1,
-                    _ => unreachable!(),
+                    c => {
+                        return Err(InputError::at_line(
+                            nx,
+                            format!("unknown air current `{c}`"),
+                        ))
+                    }
                 };
 
                 if nalt <= min_altitudes[nx][ny][ndir] {
@@ -80,16 +88,20 @@ fn fly_for(map: &[Vec<char>], nb_seconds: u32) -> u32 {
         states = next_states;
     }
 
-    states.iter().map(|&(x, y, dir)| min_altitudes[x][y][dir]).max().unwrap()
+    states
+        .iter()
+        .map(|&(x, y, dir)| min_altitudes[x][y][dir])
+        .max()
+        .ok_or_else(|| InputError::new("glider cannot keep flying"))
 }
 
-fn find_path(map: &[Vec<char>]) -> u32 {
+fn find_path(map: &[Vec<char>]) -> Result<u32, InputError> {
     let mut min_altitudes = vec![vec![vec![vec![0; 4]; 4]; map[0].len()]; map.len()];
 
-    let start_pos = find_start(map);
+    let start_pos = find_start(map)?;
 
-    for dir in 0..4 {
-        min_altitudes[start_pos.0][start_pos.1][dir][0] = 10000;
+    for dir_altitudes in &mut min_altitudes[start_pos.0][start_pos.1] {
+        dir_altitudes[0] = 10000;
     }
 
     let mut queue: VecDeque<State> = VecDeque::from_iter((0..4).map(|dir| (start_pos.0, start_pos.1, dir, 0, 0)));
@@ -111,7 +123,7 @@ fn find_path(map: &[Vec<char>]) -> u32 {
             };
 
             if ncheckpoints == 3 && map[nx][ny] == 'S' && nalt >= 10000 {
-                return time + 1;
+                return Ok(time + 1);
             }
 
             if nalt <= min_altitudes[nx][ny][ndir][ncheckpoints] {
@@ -121,11 +133,14 @@ fn find_path(map: &[Vec<char>]) -> u32 {
             queue.push_back((nx, ny, ndir, ncheckpoints, time + 1));
         }
     }
-    unreachable!()
+    Err(InputError::new("no path through all checkpoints"))
 }
 
-fn fly_for_max(map: &[Vec<char>], mut alt: u32) -> u32 {
-    let (_, start_col) = find_start(map);
+fn fly_for_max(map: &[Vec<char>], mut alt: u32) -> Result<u32, InputError> {
+    let (_, start_col) = find_start(map)?;
+    if start_col < 2 {
+        return Err(InputError::new("start `S` is too close to the left edge"));
+    }
     let mut max_dist = 0;
 
     // Map is trivial
@@ -136,11 +151,11 @@ fn fly_for_max(map: &[Vec<char>], mut alt: u32) -> u32 {
     max_dist += map.len() as u32 * (alt / 6);
     alt %= 6;
 
-    // Keep descending for the last section
+    // Keep descending for the last section, the map repeating downwards
     let mut row = 0;
     while alt > 0 {
         max_dist += 1;
-        alt = match map[row as usize][start_col - 2] {
+        alt = match map[row % map.len()][start_col - 2] {
             '+' => alt + 1,
             '-' => alt - 2,
             _ => alt - 1,
@@ -148,5 +163,5 @@ fn fly_for_max(map: &[Vec<char>], mut alt: u32) -> u32 {
         row += 1;
     }
 
-    max_dist
+    Ok(max_dist)
 }